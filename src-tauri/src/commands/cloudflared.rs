@@ -32,21 +32,24 @@ impl CloudflaredState {
 pub async fn cloudflared_check(
     state: State<'_, CloudflaredState>,
 ) -> Result<CloudflaredStatus, String> {
-    state.ensure_manager().await?;
-    
-    let lock = state.manager.read().await;
-    if let Some(manager) = lock.as_ref() {
-        let (installed, version) = manager.check_installed().await;
-        Ok(CloudflaredStatus {
-            installed,
-            version,
-            running: false,
-            url: None,
-            error: None,
-        })
-    } else {
-        Err("Manager not initialized".to_string())
-    }
+    crate::commands::last_error::track_command("cloudflared_check", async {
+        state.ensure_manager().await?;
+        
+        let lock = state.manager.read().await;
+        if let Some(manager) = lock.as_ref() {
+            let (installed, version) = manager.check_installed().await;
+            Ok(CloudflaredStatus {
+                installed,
+                version,
+                running: false,
+                url: None,
+                error: None,
+            })
+        } else {
+            Err("Manager not initialized".to_string())
+        }
+    })
+    .await
 }
 
 /// 安装cloudflared
@@ -54,14 +57,17 @@ pub async fn cloudflared_check(
 pub async fn cloudflared_install(
     state: State<'_, CloudflaredState>,
 ) -> Result<CloudflaredStatus, String> {
-    state.ensure_manager().await?;
-    
-    let lock = state.manager.read().await;
-    if let Some(manager) = lock.as_ref() {
-        manager.install().await
-    } else {
-        Err("Manager not initialized".to_string())
-    }
+    crate::commands::last_error::track_command("cloudflared_install", async {
+        state.ensure_manager().await?;
+        
+        let lock = state.manager.read().await;
+        if let Some(manager) = lock.as_ref() {
+            manager.install().await
+        } else {
+            Err("Manager not initialized".to_string())
+        }
+    })
+    .await
 }
 
 /// 启动cloudflared隧道
@@ -70,14 +76,17 @@ pub async fn cloudflared_start(
     state: State<'_, CloudflaredState>,
     config: CloudflaredConfig,
 ) -> Result<CloudflaredStatus, String> {
-    state.ensure_manager().await?;
-    
-    let lock = state.manager.read().await;
-    if let Some(manager) = lock.as_ref() {
-        manager.start(config).await
-    } else {
-        Err("Manager not initialized".to_string())
-    }
+    crate::commands::last_error::track_command("cloudflared_start", async {
+        state.ensure_manager().await?;
+        
+        let lock = state.manager.read().await;
+        if let Some(manager) = lock.as_ref() {
+            manager.start(config).await
+        } else {
+            Err("Manager not initialized".to_string())
+        }
+    })
+    .await
 }
 
 /// 停止cloudflared隧道
@@ -85,14 +94,17 @@ pub async fn cloudflared_start(
 pub async fn cloudflared_stop(
     state: State<'_, CloudflaredState>,
 ) -> Result<CloudflaredStatus, String> {
-    state.ensure_manager().await?;
-    
-    let lock = state.manager.read().await;
-    if let Some(manager) = lock.as_ref() {
-        manager.stop().await
-    } else {
-        Err("Manager not initialized".to_string())
-    }
+    crate::commands::last_error::track_command("cloudflared_stop", async {
+        state.ensure_manager().await?;
+        
+        let lock = state.manager.read().await;
+        if let Some(manager) = lock.as_ref() {
+            manager.stop().await
+        } else {
+            Err("Manager not initialized".to_string())
+        }
+    })
+    .await
 }
 
 /// 获取cloudflared状态
@@ -100,21 +112,24 @@ pub async fn cloudflared_stop(
 pub async fn cloudflared_get_status(
     state: State<'_, CloudflaredState>,
 ) -> Result<CloudflaredStatus, String> {
-    state.ensure_manager().await?;
+    crate::commands::last_error::track_command("cloudflared_get_status", async {
+        state.ensure_manager().await?;
 
-    let lock = state.manager.read().await;
-    if let Some(manager) = lock.as_ref() {
-        let (installed, version) = manager.check_installed().await;
-        let mut status = manager.get_status().await;
-        status.installed = installed;
-        status.version = version;
-        if !installed {
-            status.running = false;
-            status.url = None;
+        let lock = state.manager.read().await;
+        if let Some(manager) = lock.as_ref() {
+            let (installed, version) = manager.check_installed().await;
+            let mut status = manager.get_status().await;
+            status.installed = installed;
+            status.version = version;
+            if !installed {
+                status.running = false;
+                status.url = None;
+            }
+            Ok(status)
+        } else {
+            Ok(CloudflaredStatus::default())
         }
-        Ok(status)
-    } else {
-        Ok(CloudflaredStatus::default())
-    }
+    })
+    .await
 }
 
@@ -0,0 +1,83 @@
+// 最近一次命令错误 (跨 WebView 重载保留)
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Arc, LazyLock, Mutex};
+
+/// 最近一次失败的命令
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastError {
+    pub command: String,
+    pub error: String,
+    /// RFC 3339 时间
+    pub timestamp: String,
+}
+
+pub type LastErrorState = Arc<Mutex<Option<LastError>>>;
+
+/// 进程级状态: 命令函数无需额外的 State 参数即可记录错误
+static LAST_ERROR: LazyLock<LastErrorState> = LazyLock::new(|| Arc::new(Mutex::new(None)));
+
+fn record<T>(command: &str, result: Result<T, String>) -> Result<T, String> {
+    if let Err(e) = &result {
+        if let Ok(mut last) = LAST_ERROR.lock() {
+            *last = Some(LastError {
+                command: command.to_string(),
+                error: e.clone(),
+                timestamp: chrono::Utc::now().to_rfc3339(),
+            });
+        }
+    }
+    result
+}
+
+/// 包裹异步命令体，返回 Err 时记录为最近一次错误
+pub async fn track_command<T>(
+    command: &str,
+    body: impl Future<Output = Result<T, String>>,
+) -> Result<T, String> {
+    record(command, body.await)
+}
+
+/// 包裹同步命令体，返回 Err 时记录为最近一次错误
+pub fn track_command_sync<T>(
+    command: &str,
+    body: impl FnOnce() -> Result<T, String>,
+) -> Result<T, String> {
+    record(command, body())
+}
+
+/// 获取最近一次命令错误
+#[tauri::command]
+pub fn get_last_error() -> Option<LastError> {
+    LAST_ERROR.lock().ok().and_then(|last| last.clone())
+}
+
+/// 清除最近一次命令错误
+#[tauri::command]
+pub fn clear_last_error() {
+    if let Ok(mut last) = LAST_ERROR.lock() {
+        *last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track_command_records_errors_only() {
+        clear_last_error();
+
+        assert_eq!(track_command_sync("ok_command", || Ok::<_, String>(1)), Ok(1));
+        assert!(get_last_error().is_none());
+
+        let result: Result<(), String> = track_command_sync("failing_command", || Err("boom".to_string()));
+        assert!(result.is_err());
+        let last = get_last_error().unwrap();
+        assert_eq!(last.command, "failing_command");
+        assert_eq!(last.error, "boom");
+
+        clear_last_error();
+        assert!(get_last_error().is_none());
+    }
+}
@@ -15,11 +15,16 @@ pub mod security;
 pub mod proxy_pool;
 // 导出 user_token 命令
 pub mod user_token;
+// 导出最近一次命令错误
+pub mod last_error;
 
 /// 列出所有账号
 #[tauri::command]
 pub async fn list_accounts() -> Result<Vec<Account>, String> {
-    modules::list_accounts()
+    crate::commands::last_error::track_command("list_accounts", async {
+        modules::list_accounts()
+    })
+    .await
 }
 
 /// 添加账号
@@ -29,22 +34,25 @@ pub async fn add_account(
     _email: String,
     refresh_token: String,
 ) -> Result<Account, String> {
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app.clone()),
-    );
+    crate::commands::last_error::track_command("add_account", async {
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app.clone()),
+        );
 
-    let mut account = service.add_account(&refresh_token).await?;
+        let mut account = service.add_account(&refresh_token).await?;
 
-    // 自动刷新配额
-    let _ = internal_refresh_account_quota(&app, &mut account).await;
+        // 自动刷新配额
+        let _ = internal_refresh_account_quota(&app, &mut account).await;
 
-    // 重载账号池
-    let _ = crate::commands::proxy::reload_proxy_accounts(
-        app.state::<crate::commands::proxy::ProxyServiceState>(),
-    )
-    .await;
+        // 重载账号池
+        let _ = crate::commands::proxy::reload_proxy_accounts(
+            app.state::<crate::commands::proxy::ProxyServiceState>(),
+        )
+        .await;
 
-    Ok(account)
+        Ok(account)
+    })
+    .await
 }
 
 /// 删除账号
@@ -55,15 +63,18 @@ pub async fn delete_account(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     account_id: String,
 ) -> Result<(), String> {
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app.clone()),
-    );
-    service.delete_account(&account_id)?;
+    crate::commands::last_error::track_command("delete_account", async {
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app.clone()),
+        );
+        service.delete_account(&account_id)?;
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 /// 批量删除账号
@@ -73,22 +84,25 @@ pub async fn delete_accounts(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     account_ids: Vec<String>,
 ) -> Result<(), String> {
-    modules::logger::log_info(&format!(
-        "收到批量删除请求，共 {} 个账号",
-        account_ids.len()
-    ));
-    modules::account::delete_accounts(&account_ids).map_err(|e| {
-        modules::logger::log_error(&format!("批量删除失败: {}", e));
-        e
-    })?;
-
-    // 强制同步托盘
-    crate::modules::tray::update_tray_menus(&app);
+    crate::commands::last_error::track_command("delete_accounts", async {
+        modules::logger::log_info(&format!(
+            "收到批量删除请求，共 {} 个账号",
+            account_ids.len()
+        ));
+        modules::account::delete_accounts(&account_ids).map_err(|e| {
+            modules::logger::log_error(&format!("批量删除失败: {}", e));
+            e
+        })?;
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // 强制同步托盘
+        crate::modules::tray::update_tray_menus(&app);
 
-    Ok(())
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+
+        Ok(())
+    })
+    .await
 }
 
 /// 重新排序账号列表
@@ -98,18 +112,21 @@ pub async fn reorder_accounts(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     account_ids: Vec<String>,
 ) -> Result<(), String> {
-    modules::logger::log_info(&format!(
-        "收到账号重排序请求，共 {} 个账号",
-        account_ids.len()
-    ));
-    modules::account::reorder_accounts(&account_ids).map_err(|e| {
-        modules::logger::log_error(&format!("账号重排序失败: {}", e));
-        e
-    })?;
-
-    // Reload pool to reflect new order if running
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
-    Ok(())
+    crate::commands::last_error::track_command("reorder_accounts", async {
+        modules::logger::log_info(&format!(
+            "收到账号重排序请求，共 {} 个账号",
+            account_ids.len()
+        ));
+        modules::account::reorder_accounts(&account_ids).map_err(|e| {
+            modules::logger::log_error(&format!("账号重排序失败: {}", e));
+            e
+        })?;
+
+        // Reload pool to reflect new order if running
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        Ok(())
+    })
+    .await
 }
 
 /// 切换账号
@@ -119,37 +136,43 @@ pub async fn switch_account(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     account_id: String,
 ) -> Result<(), String> {
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app.clone()),
-    );
+    crate::commands::last_error::track_command("switch_account", async {
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app.clone()),
+        );
 
-    service.switch_account(&account_id).await?;
+        service.switch_account(&account_id).await?;
 
-    // 同步托盘
-    crate::modules::tray::update_tray_menus(&app);
+        // 同步托盘
+        crate::modules::tray::update_tray_menus(&app);
 
-    // [FIX #820] Notify proxy to clear stale session bindings and reload accounts
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // [FIX #820] Notify proxy to clear stale session bindings and reload accounts
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 /// 获取当前账号
 #[tauri::command]
 pub async fn get_current_account() -> Result<Option<Account>, String> {
-    // println!("🚀 Backend Command: get_current_account called"); // Commented out to reduce noise for frequent calls, relies on frontend log for frequency
-    // Actually user WANTS to see it.
-    modules::logger::log_info("Backend Command: get_current_account called");
-
-    let account_id = modules::get_current_account_id()?;
-
-    if let Some(id) = account_id {
-        // modules::logger::log_info(&format!("   Found current account ID: {}", id));
-        modules::load_account(&id).map(Some)
-    } else {
-        modules::logger::log_info("   No current account set");
-        Ok(None)
-    }
+    crate::commands::last_error::track_command("get_current_account", async {
+        // println!("🚀 Backend Command: get_current_account called"); // Commented out to reduce noise for frequent calls, relies on frontend log for frequency
+        // Actually user WANTS to see it.
+        modules::logger::log_info("Backend Command: get_current_account called");
+
+        let account_id = modules::get_current_account_id()?;
+
+        if let Some(id) = account_id {
+            // modules::logger::log_info(&format!("   Found current account ID: {}", id));
+            modules::load_account(&id).map(Some)
+        } else {
+            modules::logger::log_info("   No current account set");
+            Ok(None)
+        }
+    })
+    .await
 }
 
 /// 导出账号（包含 refresh_token）
@@ -157,7 +180,10 @@ use crate::models::AccountExportResponse;
 
 #[tauri::command]
 pub async fn export_accounts(account_ids: Vec<String>) -> Result<AccountExportResponse, String> {
-    modules::account::export_accounts_by_ids(&account_ids)
+    crate::commands::last_error::track_command("export_accounts", async {
+        modules::account::export_accounts_by_ids(&account_ids)
+    })
+    .await
 }
 
 /// 内部辅助功能：在添加或导入账号后自动刷新一次额度
@@ -242,14 +268,20 @@ pub async fn refresh_all_quotas(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     app_handle: tauri::AppHandle,
 ) -> Result<RefreshStats, String> {
-    refresh_all_quotas_internal(&proxy_state, Some(app_handle)).await
+    crate::commands::last_error::track_command("refresh_all_quotas", async {
+        refresh_all_quotas_internal(&proxy_state, Some(app_handle)).await
+    })
+    .await
 }
 /// 获取设备指纹（当前 storage.json + 账号绑定）
 #[tauri::command]
 pub async fn get_device_profiles(
     account_id: String,
 ) -> Result<modules::account::DeviceProfiles, String> {
-    modules::get_device_profiles(&account_id)
+    crate::commands::last_error::track_command("get_device_profiles", async {
+        modules::get_device_profiles(&account_id)
+    })
+    .await
 }
 
 /// 绑定设备指纹（capture: 采集当前；generate: 生成新指纹），并写入 storage.json
@@ -258,13 +290,19 @@ pub async fn bind_device_profile(
     account_id: String,
     mode: String,
 ) -> Result<crate::models::DeviceProfile, String> {
-    modules::bind_device_profile(&account_id, &mode)
+    crate::commands::last_error::track_command("bind_device_profile", async {
+        modules::bind_device_profile(&account_id, &mode)
+    })
+    .await
 }
 
 /// 预览生成一个指纹（不落盘）
 #[tauri::command]
 pub async fn preview_generate_profile() -> Result<crate::models::DeviceProfile, String> {
-    Ok(crate::modules::device::generate_profile())
+    crate::commands::last_error::track_command("preview_generate_profile", async {
+        Ok(crate::modules::device::generate_profile())
+    })
+    .await
 }
 
 /// 使用给定指纹直接绑定
@@ -273,7 +311,10 @@ pub async fn bind_device_profile_with_profile(
     account_id: String,
     profile: crate::models::DeviceProfile,
 ) -> Result<crate::models::DeviceProfile, String> {
-    modules::bind_device_profile_with_profile(&account_id, profile, Some("generated".to_string()))
+    crate::commands::last_error::track_command("bind_device_profile_with_profile", async {
+        modules::bind_device_profile_with_profile(&account_id, profile, Some("generated".to_string()))
+    })
+    .await
 }
 
 /// 将账号已绑定的指纹应用到 storage.json
@@ -281,13 +322,19 @@ pub async fn bind_device_profile_with_profile(
 pub async fn apply_device_profile(
     account_id: String,
 ) -> Result<crate::models::DeviceProfile, String> {
-    modules::apply_device_profile(&account_id)
+    crate::commands::last_error::track_command("apply_device_profile", async {
+        modules::apply_device_profile(&account_id)
+    })
+    .await
 }
 
 /// 恢复最早的 storage.json 备份（近似“原始”状态）
 #[tauri::command]
 pub async fn restore_original_device() -> Result<String, String> {
-    modules::restore_original_device()
+    crate::commands::last_error::track_command("restore_original_device", async {
+        modules::restore_original_device()
+    })
+    .await
 }
 
 /// 列出指纹版本
@@ -295,7 +342,10 @@ pub async fn restore_original_device() -> Result<String, String> {
 pub async fn list_device_versions(
     account_id: String,
 ) -> Result<modules::account::DeviceProfiles, String> {
-    modules::list_device_versions(&account_id)
+    crate::commands::last_error::track_command("list_device_versions", async {
+        modules::list_device_versions(&account_id)
+    })
+    .await
 }
 
 /// 按版本恢复指纹
@@ -304,32 +354,44 @@ pub async fn restore_device_version(
     account_id: String,
     version_id: String,
 ) -> Result<crate::models::DeviceProfile, String> {
-    modules::restore_device_version(&account_id, &version_id)
+    crate::commands::last_error::track_command("restore_device_version", async {
+        modules::restore_device_version(&account_id, &version_id)
+    })
+    .await
 }
 
 /// 删除历史指纹（baseline 不可删）
 #[tauri::command]
 pub async fn delete_device_version(account_id: String, version_id: String) -> Result<(), String> {
-    modules::delete_device_version(&account_id, &version_id)
+    crate::commands::last_error::track_command("delete_device_version", async {
+        modules::delete_device_version(&account_id, &version_id)
+    })
+    .await
 }
 
 /// 打开设备存储目录
 #[tauri::command]
 pub async fn open_device_folder(app: tauri::AppHandle) -> Result<(), String> {
-    let dir = modules::device::get_storage_dir()?;
-    let dir_str = dir
-        .to_str()
-        .ok_or("无法解析存储目录路径为字符串")?
-        .to_string();
-    app.opener()
-        .open_path(dir_str, None::<&str>)
-        .map_err(|e| format!("打开目录失败: {}", e))
+    crate::commands::last_error::track_command("open_device_folder", async {
+        let dir = modules::device::get_storage_dir()?;
+        let dir_str = dir
+            .to_str()
+            .ok_or("无法解析存储目录路径为字符串")?
+            .to_string();
+        app.opener()
+            .open_path(dir_str, None::<&str>)
+            .map_err(|e| format!("打开目录失败: {}", e))
+    })
+    .await
 }
 
 /// 加载配置
 #[tauri::command]
 pub async fn load_config() -> Result<AppConfig, String> {
-    modules::load_app_config()
+    crate::commands::last_error::track_command("load_config", async {
+        modules::load_app_config()
+    })
+    .await
 }
 
 /// 保存配置
@@ -339,124 +401,142 @@ pub async fn save_config(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     config: AppConfig,
 ) -> Result<(), String> {
-    modules::save_app_config(&config)?;
+    crate::commands::last_error::track_command("save_config", async {
+        modules::save_app_config(&config)?;
 
-    // 通知托盘配置已更新
-    let _ = app.emit("config://updated", ());
+        // 通知托盘配置已更新
+        let _ = app.emit("config://updated", ());
 
-    // 热更新正在运行的服务
-    let instance_lock = proxy_state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        // 更新模型映射
-        instance.axum_server.update_mapping(&config.proxy).await;
-        // 更新上游代理
-        instance
-            .axum_server
-            .update_proxy(config.proxy.upstream_proxy.clone())
-            .await;
-        // 更新安全策略 (auth)
-        instance.axum_server.update_security(&config.proxy).await;
-        // 更新 z.ai 配置
-        instance.axum_server.update_zai(&config.proxy).await;
-        // 更新实验性配置
-        instance
-            .axum_server
-            .update_experimental(&config.proxy)
-            .await;
-        // 更新调试日志配置
-        instance
-            .axum_server
-            .update_debug_logging(&config.proxy)
-            .await;
-        // [NEW] 更新 User-Agent 配置
-        instance.axum_server.update_user_agent(&config.proxy).await;
-        // 更新 Thinking Budget 配置
-        crate::proxy::update_thinking_budget_config(config.proxy.thinking_budget.clone());
-        // [NEW] 更新全局系统提示词配置
-        crate::proxy::update_global_system_prompt_config(config.proxy.global_system_prompt.clone());
-        // [NEW] 更新全局图像思维模式配置
-        crate::proxy::update_image_thinking_mode(config.proxy.image_thinking_mode.clone());
-        // 更新代理池配置
-        instance
-            .axum_server
-            .update_proxy_pool(config.proxy.proxy_pool.clone())
-            .await;
-        // 更新熔断配置
-        instance
-            .token_manager
-            .update_circuit_breaker_config(config.circuit_breaker.clone())
-            .await;
-        tracing::debug!("已同步热更新反代服务配置");
-    }
+        // 热更新正在运行的服务
+        let instance_lock = proxy_state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            // 更新模型映射
+            instance.axum_server.update_mapping(&config.proxy).await;
+            // 更新上游代理
+            instance
+                .axum_server
+                .update_proxy(config.proxy.upstream_proxy.clone())
+                .await;
+            // 更新安全策略 (auth)
+            instance.axum_server.update_security(&config.proxy).await;
+            // 更新 z.ai 配置
+            instance.axum_server.update_zai(&config.proxy).await;
+            // 更新实验性配置
+            instance
+                .axum_server
+                .update_experimental(&config.proxy)
+                .await;
+            // 更新调试日志配置
+            instance
+                .axum_server
+                .update_debug_logging(&config.proxy)
+                .await;
+            // [NEW] 更新 User-Agent 配置
+            instance.axum_server.update_user_agent(&config.proxy).await;
+            // 更新 Thinking Budget 配置
+            crate::proxy::update_thinking_budget_config(config.proxy.thinking_budget.clone());
+            // [NEW] 更新全局系统提示词配置
+            crate::proxy::update_global_system_prompt_config(config.proxy.global_system_prompt.clone());
+            // [NEW] 更新全局图像思维模式配置
+            crate::proxy::update_image_thinking_mode(config.proxy.image_thinking_mode.clone());
+            // 更新代理池配置
+            instance
+                .axum_server
+                .update_proxy_pool(config.proxy.proxy_pool.clone())
+                .await;
+            // 更新熔断配置
+            instance
+                .token_manager
+                .update_circuit_breaker_config(config.circuit_breaker.clone())
+                .await;
+            tracing::debug!("已同步热更新反代服务配置");
+        }
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 // --- OAuth 命令 ---
 
 #[tauri::command]
 pub async fn start_oauth_login(app_handle: tauri::AppHandle) -> Result<Account, String> {
-    modules::logger::log_info("开始 OAuth 授权流程...");
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
-    );
+    crate::commands::last_error::track_command("start_oauth_login", async {
+        modules::logger::log_info("开始 OAuth 授权流程...");
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
+        );
 
-    let mut account = service.start_oauth_login().await?;
+        let mut account = service.start_oauth_login().await?;
 
-    // 自动触发刷新额度
-    let _ = internal_refresh_account_quota(&app_handle, &mut account).await;
+        // 自动触发刷新额度
+        let _ = internal_refresh_account_quota(&app_handle, &mut account).await;
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(
-        app_handle.state::<crate::commands::proxy::ProxyServiceState>(),
-    )
-    .await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(
+            app_handle.state::<crate::commands::proxy::ProxyServiceState>(),
+        )
+        .await;
 
-    Ok(account)
+        Ok(account)
+    })
+    .await
 }
 
 /// 完成 OAuth 授权（不自动打开浏览器）
 #[tauri::command]
 pub async fn complete_oauth_login(app_handle: tauri::AppHandle) -> Result<Account, String> {
-    modules::logger::log_info("完成 OAuth 授权流程 (manual)...");
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
-    );
+    crate::commands::last_error::track_command("complete_oauth_login", async {
+        modules::logger::log_info("完成 OAuth 授权流程 (manual)...");
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
+        );
 
-    let mut account = service.complete_oauth_login().await?;
+        let mut account = service.complete_oauth_login().await?;
 
-    // 自动触发刷新额度
-    let _ = internal_refresh_account_quota(&app_handle, &mut account).await;
+        // 自动触发刷新额度
+        let _ = internal_refresh_account_quota(&app_handle, &mut account).await;
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(
-        app_handle.state::<crate::commands::proxy::ProxyServiceState>(),
-    )
-    .await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(
+            app_handle.state::<crate::commands::proxy::ProxyServiceState>(),
+        )
+        .await;
 
-    Ok(account)
+        Ok(account)
+    })
+    .await
 }
 
 /// 预生成 OAuth 授权链接 (不打开浏览器)
 #[tauri::command]
 pub async fn prepare_oauth_url(app_handle: tauri::AppHandle) -> Result<String, String> {
-    let service = modules::account_service::AccountService::new(
-        crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
-    );
-    service.prepare_oauth_url().await
+    crate::commands::last_error::track_command("prepare_oauth_url", async {
+        let service = modules::account_service::AccountService::new(
+            crate::modules::integration::SystemManager::Desktop(app_handle.clone()),
+        );
+        service.prepare_oauth_url().await
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn cancel_oauth_login() -> Result<(), String> {
-    modules::oauth_server::cancel_oauth_flow();
-    Ok(())
+    crate::commands::last_error::track_command("cancel_oauth_login", async {
+        modules::oauth_server::cancel_oauth_flow();
+        Ok(())
+    })
+    .await
 }
 
 /// 手动提交 OAuth Code (用于 Docker/远程环境无法自动回调时)
 #[tauri::command]
 pub async fn submit_oauth_code(code: String, state: Option<String>) -> Result<(), String> {
-    modules::logger::log_info("收到手动提交 OAuth Code 请求");
-    modules::oauth_server::submit_oauth_code(code, state).await
+    crate::commands::last_error::track_command("submit_oauth_code", async {
+        modules::logger::log_info("收到手动提交 OAuth Code 请求");
+        modules::oauth_server::submit_oauth_code(code, state).await
+    })
+    .await
 }
 
 // --- 导入命令 ---
@@ -466,17 +546,20 @@ pub async fn import_v1_accounts(
     app: tauri::AppHandle,
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
 ) -> Result<Vec<Account>, String> {
-    let accounts = modules::migration::import_from_v1().await?;
+    crate::commands::last_error::track_command("import_v1_accounts", async {
+        let accounts = modules::migration::import_from_v1().await?;
 
-    // 对导入的账号尝试刷新一波
-    for mut account in accounts.clone() {
-        let _ = internal_refresh_account_quota(&app, &mut account).await;
-    }
+        // 对导入的账号尝试刷新一波
+        for mut account in accounts.clone() {
+            let _ = internal_refresh_account_quota(&app, &mut account).await;
+        }
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
 
-    Ok(accounts)
+        Ok(accounts)
+    })
+    .await
 }
 
 #[tauri::command]
@@ -484,23 +567,26 @@ pub async fn import_from_db(
     app: tauri::AppHandle,
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
 ) -> Result<Account, String> {
-    // 同步函数包装为 async
-    let mut account = modules::migration::import_from_db().await?;
+    crate::commands::last_error::track_command("import_from_db", async {
+        // 同步函数包装为 async
+        let mut account = modules::migration::import_from_db().await?;
 
-    // 既然是从数据库导入（即 IDE 当前账号），自动将其设为 Manager 的当前账号
-    let account_id = account.id.clone();
-    modules::account::set_current_account_id(&account_id)?;
+        // 既然是从数据库导入（即 IDE 当前账号），自动将其设为 Manager 的当前账号
+        let account_id = account.id.clone();
+        modules::account::set_current_account_id(&account_id)?;
 
-    // 自动触发刷新额度
-    let _ = internal_refresh_account_quota(&app, &mut account).await;
+        // 自动触发刷新额度
+        let _ = internal_refresh_account_quota(&app, &mut account).await;
 
-    // 刷新托盘图标展示
-    crate::modules::tray::update_tray_menus(&app);
+        // 刷新托盘图标展示
+        crate::modules::tray::update_tray_menus(&app);
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
 
-    Ok(account)
+        Ok(account)
+    })
+    .await
 }
 
 #[tauri::command]
@@ -510,23 +596,26 @@ pub async fn import_custom_db(
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
     path: String,
 ) -> Result<Account, String> {
-    // 调用重构后的自定义导入函数
-    let mut account = modules::migration::import_from_custom_db_path(path).await?;
+    crate::commands::last_error::track_command("import_custom_db", async {
+        // 调用重构后的自定义导入函数
+        let mut account = modules::migration::import_from_custom_db_path(path).await?;
 
-    // 自动设为当前账号
-    let account_id = account.id.clone();
-    modules::account::set_current_account_id(&account_id)?;
+        // 自动设为当前账号
+        let account_id = account.id.clone();
+        modules::account::set_current_account_id(&account_id)?;
 
-    // 自动触发刷新额度
-    let _ = internal_refresh_account_quota(&app, &mut account).await;
+        // 自动触发刷新额度
+        let _ = internal_refresh_account_quota(&app, &mut account).await;
 
-    // 刷新托盘图标展示
-    crate::modules::tray::update_tray_menus(&app);
+        // 刷新托盘图标展示
+        crate::modules::tray::update_tray_menus(&app);
 
-    // Reload token pool
-    let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
+        // Reload token pool
+        let _ = crate::commands::proxy::reload_proxy_accounts(proxy_state).await;
 
-    Ok(account)
+        Ok(account)
+    })
+    .await
 }
 
 #[tauri::command]
@@ -534,36 +623,39 @@ pub async fn sync_account_from_db(
     app: tauri::AppHandle,
     proxy_state: tauri::State<'_, crate::commands::proxy::ProxyServiceState>,
 ) -> Result<Option<Account>, String> {
-    // 1. 获取 DB 中的 Refresh Token
-    let db_refresh_token = match modules::migration::get_refresh_token_from_db() {
-        Ok(token) => token,
-        Err(e) => {
-            modules::logger::log_info(&format!("自动同步跳过: {}", e));
-            return Ok(None);
-        }
-    };
+    crate::commands::last_error::track_command("sync_account_from_db", async {
+        // 1. 获取 DB 中的 Refresh Token
+        let db_refresh_token = match modules::migration::get_refresh_token_from_db() {
+            Ok(token) => token,
+            Err(e) => {
+                modules::logger::log_info(&format!("自动同步跳过: {}", e));
+                return Ok(None);
+            }
+        };
 
-    // 2. 获取 Manager 当前账号
-    let curr_account = modules::account::get_current_account()?;
+        // 2. 获取 Manager 当前账号
+        let curr_account = modules::account::get_current_account()?;
 
-    // 3. 对比：如果 Refresh Token 相同，说明账号没变，无需导入
-    if let Some(acc) = curr_account {
-        if acc.token.refresh_token == db_refresh_token {
-            // 账号未变，由于已经是周期性任务，我们可以选择性刷新一下配额，或者直接返回
-            // 这里为了节省 API 流量，直接返回
-            return Ok(None);
+        // 3. 对比：如果 Refresh Token 相同，说明账号没变，无需导入
+        if let Some(acc) = curr_account {
+            if acc.token.refresh_token == db_refresh_token {
+                // 账号未变，由于已经是周期性任务，我们可以选择性刷新一下配额，或者直接返回
+                // 这里为了节省 API 流量，直接返回
+                return Ok(None);
+            }
+            modules::logger::log_info(&format!(
+                "检测到账号切换 ({} -> DB新账号)，正在同步...",
+                acc.email
+            ));
+        } else {
+            modules::logger::log_info("检测到新登录账号，正在自动同步...");
         }
-        modules::logger::log_info(&format!(
-            "检测到账号切换 ({} -> DB新账号)，正在同步...",
-            acc.email
-        ));
-    } else {
-        modules::logger::log_info("检测到新登录账号，正在自动同步...");
-    }
 
-    // 4. 执行完整导入
-    let account = import_from_db(app, proxy_state).await?;
-    Ok(Some(account))
+        // 4. 执行完整导入
+        let account = import_from_db(app, proxy_state).await?;
+        Ok(Some(account))
+    })
+    .await
 }
 
 fn validate_path(path: &str) -> Result<(), String> {
@@ -597,126 +689,172 @@ fn validate_path(path: &str) -> Result<(), String> {
 /// 保存文本文件 (绕过前端 Scope 限制)
 #[tauri::command]
 pub async fn save_text_file(path: String, content: String) -> Result<(), String> {
-    validate_path(&path)?;
-    std::fs::write(&path, content).map_err(|e| format!("写入文件失败: {}", e))
+    crate::commands::last_error::track_command("save_text_file", async {
+        validate_path(&path)?;
+        std::fs::write(&path, content).map_err(|e| format!("写入文件失败: {}", e))
+    })
+    .await
 }
 
 /// 读取文本文件 (绕过前端 Scope 限制)
 #[tauri::command]
 pub async fn read_text_file(path: String) -> Result<String, String> {
-    validate_path(&path)?;
-    std::fs::read_to_string(&path).map_err(|e| format!("读取文件失败: {}", e))
+    crate::commands::last_error::track_command("read_text_file", async {
+        validate_path(&path)?;
+        std::fs::read_to_string(&path).map_err(|e| format!("读取文件失败: {}", e))
+    })
+    .await
 }
 
 /// 清理日志缓存
 #[tauri::command]
 pub async fn clear_log_cache() -> Result<(), String> {
-    modules::logger::clear_logs()
+    crate::commands::last_error::track_command("clear_log_cache", async {
+        modules::logger::clear_logs()
+    })
+    .await
 }
 
 /// 清理 Antigravity 应用缓存
 /// 用于解决登录失败、版本验证错误等问题
 #[tauri::command]
 pub async fn clear_antigravity_cache() -> Result<modules::cache::ClearResult, String> {
-    modules::cache::clear_antigravity_cache(None)
+    crate::commands::last_error::track_command("clear_antigravity_cache", async {
+        modules::cache::clear_antigravity_cache(None)
+    })
+    .await
 }
 
 /// 获取 Antigravity 缓存路径列表（用于预览）
 #[tauri::command]
 pub async fn get_antigravity_cache_paths() -> Result<Vec<String>, String> {
-    Ok(modules::cache::get_existing_cache_paths()
-        .into_iter()
-        .map(|p| p.to_string_lossy().to_string())
-        .collect())
+    crate::commands::last_error::track_command("get_antigravity_cache_paths", async {
+        Ok(modules::cache::get_existing_cache_paths()
+            .into_iter()
+            .map(|p| p.to_string_lossy().to_string())
+            .collect())
+    })
+    .await
 }
 
 /// 打开数据目录
 #[tauri::command]
 pub async fn open_data_folder() -> Result<(), String> {
-    let path = modules::account::get_data_dir()?;
-
-    #[cfg(target_os = "macos")]
-    {
-        std::process::Command::new("open")
-            .arg(path)
-            .spawn()
-            .map_err(|e| format!("打开文件夹失败: {}", e))?;
-    }
+    crate::commands::last_error::track_command("open_data_folder", async {
+        let path = modules::account::get_data_dir()?;
+
+        #[cfg(target_os = "macos")]
+        {
+            std::process::Command::new("open")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("打开文件夹失败: {}", e))?;
+        }
 
-    #[cfg(target_os = "windows")]
-    {
-        std::process::Command::new("explorer")
-            .arg(path)
-            .spawn()
-            .map_err(|e| format!("打开文件夹失败: {}", e))?;
-    }
+        #[cfg(target_os = "windows")]
+        {
+            std::process::Command::new("explorer")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("打开文件夹失败: {}", e))?;
+        }
 
-    #[cfg(target_os = "linux")]
-    {
-        std::process::Command::new("xdg-open")
-            .arg(path)
-            .spawn()
-            .map_err(|e| format!("打开文件夹失败: {}", e))?;
-    }
+        #[cfg(target_os = "linux")]
+        {
+            std::process::Command::new("xdg-open")
+                .arg(path)
+                .spawn()
+                .map_err(|e| format!("打开文件夹失败: {}", e))?;
+        }
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 /// 获取数据目录绝对路径
 #[tauri::command]
 pub async fn get_data_dir_path() -> Result<String, String> {
-    let path = modules::account::get_data_dir()?;
-    Ok(path.to_string_lossy().to_string())
+    crate::commands::last_error::track_command("get_data_dir_path", async {
+        let path = modules::account::get_data_dir()?;
+        Ok(path.to_string_lossy().to_string())
+    })
+    .await
+}
+
+/// 获取规范化后的应用数据目录 (解析符号链接，失败时回退为原始路径)
+#[tauri::command]
+pub async fn get_app_data_dir() -> Result<String, String> {
+    crate::commands::last_error::track_command("get_app_data_dir", async {
+        let path = modules::account::get_data_dir()?;
+        let canonical = std::fs::canonicalize(&path).unwrap_or(path);
+        let display = canonical.to_string_lossy().to_string();
+        // Windows canonicalize 会返回 `\\?\` 前缀的扩展路径，前端展示/打开时去掉
+        Ok(display.strip_prefix(r"\\?\").map(str::to_string).unwrap_or(display))
+    })
+    .await
 }
 
 /// 显示主窗口
 #[tauri::command]
 pub async fn show_main_window(window: tauri::Window) -> Result<(), String> {
-    window.show().map_err(|e| e.to_string())
+    crate::commands::last_error::track_command("show_main_window", async {
+        window.show().map_err(|e| e.to_string())
+    })
+    .await
 }
 
 /// 设置窗口主题（用于同步 Windows 标题栏按钮颜色）
 #[tauri::command]
 pub async fn set_window_theme(window: tauri::Window, theme: String) -> Result<(), String> {
-    use tauri::Theme;
+    crate::commands::last_error::track_command("set_window_theme", async {
+        use tauri::Theme;
 
-    let tauri_theme = match theme.as_str() {
-        "dark" => Some(Theme::Dark),
-        "light" => Some(Theme::Light),
-        _ => None, // system default
-    };
+        let tauri_theme = match theme.as_str() {
+            "dark" => Some(Theme::Dark),
+            "light" => Some(Theme::Light),
+            _ => None, // system default
+        };
 
-    window.set_theme(tauri_theme).map_err(|e| e.to_string())
+        window.set_theme(tauri_theme).map_err(|e| e.to_string())
+    })
+    .await
 }
 
 /// 获取 Antigravity 可执行文件路径
 #[tauri::command]
 pub async fn get_antigravity_path(bypass_config: Option<bool>) -> Result<String, String> {
-    // 1. 优先从配置查询 (除非明确要求绕过)
-    if bypass_config != Some(true) {
-        if let Ok(config) = crate::modules::config::load_app_config() {
-            if let Some(path) = config.antigravity_executable {
-                if std::path::Path::new(&path).exists() {
-                    return Ok(path);
+    crate::commands::last_error::track_command("get_antigravity_path", async {
+        // 1. 优先从配置查询 (除非明确要求绕过)
+        if bypass_config != Some(true) {
+            if let Ok(config) = crate::modules::config::load_app_config() {
+                if let Some(path) = config.antigravity_executable {
+                    if std::path::Path::new(&path).exists() {
+                        return Ok(path);
+                    }
                 }
             }
         }
-    }
 
-    // 2. 执行实时探测
-    match crate::modules::process::get_antigravity_executable_path() {
-        Some(path) => Ok(path.to_string_lossy().to_string()),
-        None => Err("未找到 Antigravity 安装路径".to_string()),
-    }
+        // 2. 执行实时探测
+        match crate::modules::process::get_antigravity_executable_path() {
+            Some(path) => Ok(path.to_string_lossy().to_string()),
+            None => Err("未找到 Antigravity 安装路径".to_string()),
+        }
+    })
+    .await
 }
 
 /// 获取 Antigravity 启动参数
 #[tauri::command]
 pub async fn get_antigravity_args() -> Result<Vec<String>, String> {
-    match crate::modules::process::get_args_from_running_process() {
-        Some(args) => Ok(args),
-        None => Err("未找到正在运行的 Antigravity 进程".to_string()),
-    }
+    crate::commands::last_error::track_command("get_antigravity_args", async {
+        match crate::modules::process::get_args_from_running_process() {
+            Some(args) => Ok(args),
+            None => Err("未找到正在运行的 Antigravity 进程".to_string()),
+        }
+    })
+    .await
 }
 
 /// 检测更新响应结构
@@ -725,35 +863,50 @@ pub use crate::modules::update_checker::UpdateInfo;
 /// 检测 GitHub releases 更新
 #[tauri::command]
 pub async fn check_for_updates() -> Result<UpdateInfo, String> {
-    modules::logger::log_info("收到前端触发的更新检查请求");
-    crate::modules::update_checker::check_for_updates().await
+    crate::commands::last_error::track_command("check_for_updates", async {
+        modules::logger::log_info("收到前端触发的更新检查请求");
+        crate::modules::update_checker::check_for_updates().await
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn should_check_updates() -> Result<bool, String> {
-    let settings = crate::modules::update_checker::load_update_settings()?;
-    Ok(crate::modules::update_checker::should_check_for_updates(
-        &settings,
-    ))
+    crate::commands::last_error::track_command("should_check_updates", async {
+        let settings = crate::modules::update_checker::load_update_settings()?;
+        Ok(crate::modules::update_checker::should_check_for_updates(
+            &settings,
+        ))
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn update_last_check_time() -> Result<(), String> {
-    crate::modules::update_checker::update_last_check_time()
+    crate::commands::last_error::track_command("update_last_check_time", async {
+        crate::modules::update_checker::update_last_check_time()
+    })
+    .await
 }
 
 
 /// 检测是否通过 Homebrew Cask 安装
 #[tauri::command]
 pub async fn check_homebrew_installation() -> Result<bool, String> {
-    Ok(crate::modules::update_checker::is_homebrew_installed())
+    crate::commands::last_error::track_command("check_homebrew_installation", async {
+        Ok(crate::modules::update_checker::is_homebrew_installed())
+    })
+    .await
 }
 
 /// 通过 Homebrew Cask 升级应用
 #[tauri::command]
 pub async fn brew_upgrade_cask() -> Result<String, String> {
-    modules::logger::log_info("收到前端触发的 Homebrew 升级请求");
-    crate::modules::update_checker::brew_upgrade_cask().await
+    crate::commands::last_error::track_command("brew_upgrade_cask", async {
+        modules::logger::log_info("收到前端触发的 Homebrew 升级请求");
+        crate::modules::update_checker::brew_upgrade_cask().await
+    })
+    .await
 }
 
 
@@ -761,7 +914,10 @@ pub async fn brew_upgrade_cask() -> Result<String, String> {
 #[tauri::command]
 pub async fn get_update_settings() -> Result<crate::modules::update_checker::UpdateSettings, String>
 {
-    crate::modules::update_checker::load_update_settings()
+    crate::commands::last_error::track_command("get_update_settings", async {
+        crate::modules::update_checker::load_update_settings()
+    })
+    .await
 }
 
 /// 保存更新设置
@@ -769,7 +925,10 @@ pub async fn get_update_settings() -> Result<crate::modules::update_checker::Upd
 pub async fn save_update_settings(
     settings: crate::modules::update_checker::UpdateSettings,
 ) -> Result<(), String> {
-    crate::modules::update_checker::save_update_settings(&settings)
+    crate::commands::last_error::track_command("save_update_settings", async {
+        crate::modules::update_checker::save_update_settings(&settings)
+    })
+    .await
 }
 
 /// 切换账号的反代禁用状态
@@ -781,152 +940,164 @@ pub async fn toggle_proxy_status(
     enable: bool,
     reason: Option<String>,
 ) -> Result<(), String> {
-    modules::logger::log_info(&format!(
-        "切换账号反代状态: {} -> {}",
-        account_id,
-        if enable { "启用" } else { "禁用" }
-    ));
-
-    // 1. 读取账号文件
-    let data_dir = modules::account::get_data_dir()?;
-    let account_path = data_dir
-        .join("accounts")
-        .join(format!("{}.json", account_id));
-
-    if !account_path.exists() {
-        return Err(format!("账号文件不存在: {}", account_id));
-    }
+    crate::commands::last_error::track_command("toggle_proxy_status", async {
+        modules::logger::log_info(&format!(
+            "切换账号反代状态: {} -> {}",
+            account_id,
+            if enable { "启用" } else { "禁用" }
+        ));
 
-    let content =
-        std::fs::read_to_string(&account_path).map_err(|e| format!("读取账号文件失败: {}", e))?;
-
-    let mut account_json: serde_json::Value =
-        serde_json::from_str(&content).map_err(|e| format!("解析账号文件失败: {}", e))?;
-
-    // 2. 更新 proxy_disabled 字段
-    if enable {
-        // 启用反代
-        account_json["proxy_disabled"] = serde_json::Value::Bool(false);
-        account_json["proxy_disabled_reason"] = serde_json::Value::Null;
-        account_json["proxy_disabled_at"] = serde_json::Value::Null;
-    } else {
-        // 禁用反代
-        let now = chrono::Utc::now().timestamp();
-        account_json["proxy_disabled"] = serde_json::Value::Bool(true);
-        account_json["proxy_disabled_at"] = serde_json::Value::Number(now.into());
-        account_json["proxy_disabled_reason"] =
-            serde_json::Value::String(reason.unwrap_or_else(|| "用户手动禁用".to_string()));
-    }
+        // 1. 读取账号文件
+        let data_dir = modules::account::get_data_dir()?;
+        let account_path = data_dir
+            .join("accounts")
+            .join(format!("{}.json", account_id));
 
-    // 3. 保存到磁盘
-    let json_str = serde_json::to_string_pretty(&account_json)
-        .map_err(|e| format!("序列化账号数据失败: {}", e))?;
-    std::fs::write(&account_path, json_str).map_err(|e| format!("写入账号文件失败: {}", e))?;
+        if !account_path.exists() {
+            return Err(format!("账号文件不存在: {}", account_id));
+        }
 
-    modules::logger::log_info(&format!(
-        "账号反代状态已更新: {} ({})",
-        account_id,
-        if enable { "已启用" } else { "已禁用" }
-    ));
+        let content =
+            std::fs::read_to_string(&account_path).map_err(|e| format!("读取账号文件失败: {}", e))?;
 
-    // 4. 如果反代服务正在运行,立刻同步到内存池（避免禁用后仍被选中）
-    {
-        let instance_lock = proxy_state.instance.read().await;
-        if let Some(instance) = instance_lock.as_ref() {
-            // 如果禁用的是当前固定账号，则自动关闭固定模式（内存 + 配置持久化）
-            if !enable {
-                let pref_id = instance.token_manager.get_preferred_account().await;
-                if pref_id.as_deref() == Some(&account_id) {
-                    instance.token_manager.set_preferred_account(None).await;
-
-                    if let Ok(mut cfg) = crate::modules::config::load_app_config() {
-                        if cfg.proxy.preferred_account_id.as_deref() == Some(&account_id) {
-                            cfg.proxy.preferred_account_id = None;
-                            let _ = crate::modules::config::save_app_config(&cfg);
+        let mut account_json: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| format!("解析账号文件失败: {}", e))?;
+
+        // 2. 更新 proxy_disabled 字段
+        if enable {
+            // 启用反代
+            account_json["proxy_disabled"] = serde_json::Value::Bool(false);
+            account_json["proxy_disabled_reason"] = serde_json::Value::Null;
+            account_json["proxy_disabled_at"] = serde_json::Value::Null;
+        } else {
+            // 禁用反代
+            let now = chrono::Utc::now().timestamp();
+            account_json["proxy_disabled"] = serde_json::Value::Bool(true);
+            account_json["proxy_disabled_at"] = serde_json::Value::Number(now.into());
+            account_json["proxy_disabled_reason"] =
+                serde_json::Value::String(reason.unwrap_or_else(|| "用户手动禁用".to_string()));
+        }
+
+        // 3. 保存到磁盘
+        let json_str = serde_json::to_string_pretty(&account_json)
+            .map_err(|e| format!("序列化账号数据失败: {}", e))?;
+        std::fs::write(&account_path, json_str).map_err(|e| format!("写入账号文件失败: {}", e))?;
+
+        modules::logger::log_info(&format!(
+            "账号反代状态已更新: {} ({})",
+            account_id,
+            if enable { "已启用" } else { "已禁用" }
+        ));
+
+        // 4. 如果反代服务正在运行,立刻同步到内存池（避免禁用后仍被选中）
+        {
+            let instance_lock = proxy_state.instance.read().await;
+            if let Some(instance) = instance_lock.as_ref() {
+                // 如果禁用的是当前固定账号，则自动关闭固定模式（内存 + 配置持久化）
+                if !enable {
+                    let pref_id = instance.token_manager.get_preferred_account().await;
+                    if pref_id.as_deref() == Some(&account_id) {
+                        instance.token_manager.set_preferred_account(None).await;
+
+                        if let Ok(mut cfg) = crate::modules::config::load_app_config() {
+                            if cfg.proxy.preferred_account_id.as_deref() == Some(&account_id) {
+                                cfg.proxy.preferred_account_id = None;
+                                let _ = crate::modules::config::save_app_config(&cfg);
+                            }
                         }
                     }
                 }
-            }
 
-            instance
-                .token_manager
-                .reload_account(&account_id)
-                .await
-                .map_err(|e| format!("同步账号失败: {}", e))?;
+                instance
+                    .token_manager
+                    .reload_account(&account_id)
+                    .await
+                    .map_err(|e| format!("同步账号失败: {}", e))?;
+            }
         }
-    }
 
-    // 5. 更新托盘菜单
-    crate::modules::tray::update_tray_menus(&app);
+        // 5. 更新托盘菜单
+        crate::modules::tray::update_tray_menus(&app);
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 /// 预热所有可用账号
 #[tauri::command]
 pub async fn warm_up_all_accounts() -> Result<String, String> {
-    modules::quota::warm_up_all_accounts().await
+    crate::commands::last_error::track_command("warm_up_all_accounts", async {
+        modules::quota::warm_up_all_accounts().await
+    })
+    .await
 }
 
 /// 预热指定账号
 #[tauri::command]
 pub async fn warm_up_account(account_id: String) -> Result<String, String> {
-    modules::quota::warm_up_account(&account_id).await
+    crate::commands::last_error::track_command("warm_up_account", async {
+        modules::quota::warm_up_account(&account_id).await
+    })
+    .await
 }
 
 /// 更新账号自定义标签
 #[tauri::command]
 pub async fn update_account_label(account_id: String, label: String) -> Result<(), String> {
-    // 验证标签长度（按字符数计算，支持中文）
-    if label.chars().count() > 15 {
-        return Err("标签长度不能超过15个字符".to_string());
-    }
-
-    modules::logger::log_info(&format!(
-        "更新账号标签: {} -> {:?}",
-        account_id,
-        if label.is_empty() { "无" } else { &label }
-    ));
-
-    // 1. 读取账号文件
-    let data_dir = modules::account::get_data_dir()?;
-    let account_path = data_dir
-        .join("accounts")
-        .join(format!("{}.json", account_id));
+    crate::commands::last_error::track_command("update_account_label", async {
+        // 验证标签长度（按字符数计算，支持中文）
+        if label.chars().count() > 15 {
+            return Err("标签长度不能超过15个字符".to_string());
+        }
 
-    if !account_path.exists() {
-        return Err(format!("账号文件不存在: {}", account_id));
-    }
+        modules::logger::log_info(&format!(
+            "更新账号标签: {} -> {:?}",
+            account_id,
+            if label.is_empty() { "无" } else { &label }
+        ));
 
-    let content =
-        std::fs::read_to_string(&account_path).map_err(|e| format!("读取账号文件失败: {}", e))?;
+        // 1. 读取账号文件
+        let data_dir = modules::account::get_data_dir()?;
+        let account_path = data_dir
+            .join("accounts")
+            .join(format!("{}.json", account_id));
 
-    let mut account_json: serde_json::Value =
-        serde_json::from_str(&content).map_err(|e| format!("解析账号文件失败: {}", e))?;
+        if !account_path.exists() {
+            return Err(format!("账号文件不存在: {}", account_id));
+        }
 
-    // 2. 更新 custom_label 字段
-    if label.is_empty() {
-        account_json["custom_label"] = serde_json::Value::Null;
-    } else {
-        account_json["custom_label"] = serde_json::Value::String(label.clone());
-    }
+        let content =
+            std::fs::read_to_string(&account_path).map_err(|e| format!("读取账号文件失败: {}", e))?;
 
-    // 3. 保存到磁盘
-    let json_str = serde_json::to_string_pretty(&account_json)
-        .map_err(|e| format!("序列化账号数据失败: {}", e))?;
-    std::fs::write(&account_path, json_str).map_err(|e| format!("写入账号文件失败: {}", e))?;
+        let mut account_json: serde_json::Value =
+            serde_json::from_str(&content).map_err(|e| format!("解析账号文件失败: {}", e))?;
 
-    modules::logger::log_info(&format!(
-        "账号标签已更新: {} ({})",
-        account_id,
+        // 2. 更新 custom_label 字段
         if label.is_empty() {
-            "已清除".to_string()
+            account_json["custom_label"] = serde_json::Value::Null;
         } else {
-            label
+            account_json["custom_label"] = serde_json::Value::String(label.clone());
         }
-    ));
 
-    Ok(())
+        // 3. 保存到磁盘
+        let json_str = serde_json::to_string_pretty(&account_json)
+            .map_err(|e| format!("序列化账号数据失败: {}", e))?;
+        std::fs::write(&account_path, json_str).map_err(|e| format!("写入账号文件失败: {}", e))?;
+
+        modules::logger::log_info(&format!(
+            "账号标签已更新: {} ({})",
+            account_id,
+            if label.is_empty() {
+                "已清除".to_string()
+            } else {
+                label
+            }
+        ));
+
+        Ok(())
+    })
+    .await
 }
 
 // ============================================================================
@@ -936,7 +1107,10 @@ pub async fn update_account_label(account_id: String, label: String) -> Result<(
 /// 获取 HTTP API 设置
 #[tauri::command]
 pub async fn get_http_api_settings() -> Result<crate::modules::http_api::HttpApiSettings, String> {
-    crate::modules::http_api::load_settings()
+    crate::commands::last_error::track_command("get_http_api_settings", async {
+        crate::modules::http_api::load_settings()
+    })
+    .await
 }
 
 /// 保存 HTTP API 设置
@@ -944,7 +1118,10 @@ pub async fn get_http_api_settings() -> Result<crate::modules::http_api::HttpApi
 pub async fn save_http_api_settings(
     settings: crate::modules::http_api::HttpApiSettings,
 ) -> Result<(), String> {
-    crate::modules::http_api::save_settings(&settings)
+    crate::commands::last_error::track_command("save_http_api_settings", async {
+        crate::modules::http_api::save_settings(&settings)
+    })
+    .await
 }
 
 // ============================================================================
@@ -955,60 +1132,90 @@ pub use crate::modules::token_stats::{AccountTokenStats, TokenStatsAggregated, T
 
 #[tauri::command]
 pub async fn get_token_stats_hourly(hours: i64) -> Result<Vec<TokenStatsAggregated>, String> {
-    crate::modules::token_stats::get_hourly_stats(hours)
+    crate::commands::last_error::track_command("get_token_stats_hourly", async {
+        crate::modules::token_stats::get_hourly_stats(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_daily(days: i64) -> Result<Vec<TokenStatsAggregated>, String> {
-    crate::modules::token_stats::get_daily_stats(days)
+    crate::commands::last_error::track_command("get_token_stats_daily", async {
+        crate::modules::token_stats::get_daily_stats(days)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_weekly(weeks: i64) -> Result<Vec<TokenStatsAggregated>, String> {
-    crate::modules::token_stats::get_weekly_stats(weeks)
+    crate::commands::last_error::track_command("get_token_stats_weekly", async {
+        crate::modules::token_stats::get_weekly_stats(weeks)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_by_account(hours: i64) -> Result<Vec<AccountTokenStats>, String> {
-    crate::modules::token_stats::get_account_stats(hours)
+    crate::commands::last_error::track_command("get_token_stats_by_account", async {
+        crate::modules::token_stats::get_account_stats(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_summary(hours: i64) -> Result<TokenStatsSummary, String> {
-    crate::modules::token_stats::get_summary_stats(hours)
+    crate::commands::last_error::track_command("get_token_stats_summary", async {
+        crate::modules::token_stats::get_summary_stats(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_by_model(
     hours: i64,
 ) -> Result<Vec<crate::modules::token_stats::ModelTokenStats>, String> {
-    crate::modules::token_stats::get_model_stats(hours)
+    crate::commands::last_error::track_command("get_token_stats_by_model", async {
+        crate::modules::token_stats::get_model_stats(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_model_trend_hourly(
     hours: i64,
 ) -> Result<Vec<crate::modules::token_stats::ModelTrendPoint>, String> {
-    crate::modules::token_stats::get_model_trend_hourly(hours)
+    crate::commands::last_error::track_command("get_token_stats_model_trend_hourly", async {
+        crate::modules::token_stats::get_model_trend_hourly(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_model_trend_daily(
     days: i64,
 ) -> Result<Vec<crate::modules::token_stats::ModelTrendPoint>, String> {
-    crate::modules::token_stats::get_model_trend_daily(days)
+    crate::commands::last_error::track_command("get_token_stats_model_trend_daily", async {
+        crate::modules::token_stats::get_model_trend_daily(days)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_account_trend_hourly(
     hours: i64,
 ) -> Result<Vec<crate::modules::token_stats::AccountTrendPoint>, String> {
-    crate::modules::token_stats::get_account_trend_hourly(hours)
+    crate::commands::last_error::track_command("get_token_stats_account_trend_hourly", async {
+        crate::modules::token_stats::get_account_trend_hourly(hours)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_token_stats_account_trend_daily(
     days: i64,
 ) -> Result<Vec<crate::modules::token_stats::AccountTrendPoint>, String> {
-    crate::modules::token_stats::get_account_trend_daily(days)
+    crate::commands::last_error::track_command("get_token_stats_account_trend_daily", async {
+        crate::modules::token_stats::get_account_trend_daily(days)
+    })
+    .await
 }
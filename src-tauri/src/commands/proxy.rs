@@ -1,4 +1,5 @@
 use crate::proxy::monitor::{ProxyMonitor, ProxyRequestLog, ProxyStats};
+use crate::proxy::rate_limit::RateLimitConfig;
 use crate::proxy::{ProxyConfig, ProxyPoolConfig, TokenManager};
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -23,6 +24,7 @@ pub struct ProxyServiceState {
     pub monitor: Arc<RwLock<Option<Arc<ProxyMonitor>>>>,
     pub admin_server: Arc<RwLock<Option<AdminServerInstance>>>, // [NEW] 常驻管理服务器
     pub starting: Arc<AtomicBool>, // [NEW] 标识是否正在启动中，防止死锁
+    pub rate_limit_config: Arc<RwLock<RateLimitConfig>>, // 限流参数，启动时同步到 TokenManager
 }
 
 pub struct AdminServerInstance {
@@ -47,6 +49,7 @@ impl ProxyServiceState {
             monitor: Arc::new(RwLock::new(None)),
             admin_server: Arc::new(RwLock::new(None)),
             starting: Arc::new(AtomicBool::new(false)),
+            rate_limit_config: Arc::new(RwLock::new(RateLimitConfig::default())),
         }
     }
 }
@@ -59,12 +62,15 @@ pub async fn start_proxy_service(
     cf_state: State<'_, crate::commands::cloudflared::CloudflaredState>,
     app_handle: tauri::AppHandle,
 ) -> Result<ProxyStatus, String> {
-    internal_start_proxy_service(
-        config,
-        &state,
-        crate::modules::integration::SystemManager::Desktop(app_handle),
-        Arc::new(cf_state.inner().clone()),
-    )
+    crate::commands::last_error::track_command("start_proxy_service", async {
+        internal_start_proxy_service(
+            config,
+            &state,
+            crate::modules::integration::SystemManager::Desktop(app_handle),
+            Arc::new(cf_state.inner().clone()),
+        )
+        .await
+    })
     .await
 }
 
@@ -154,6 +160,7 @@ pub async fn internal_start_proxy_service(
     token_manager
         .update_circuit_breaker_config(app_config.circuit_breaker)
         .await;
+    token_manager.update_rate_limit_config(*state.rate_limit_config.read().await);
 
     // 🆕 [FIX #820] 恢复固定账号模式设置
     if let Some(ref account_id) = config.preferred_account_id {
@@ -281,74 +288,99 @@ pub async fn ensure_admin_server(
 /// 停止反代服务
 #[tauri::command]
 pub async fn stop_proxy_service(state: State<'_, ProxyServiceState>) -> Result<(), String> {
-    let mut instance_lock = state.instance.write().await;
+    crate::commands::last_error::track_command("stop_proxy_service", async {
+        let mut instance_lock = state.instance.write().await;
 
-    if instance_lock.is_none() {
-        return Err("服务未运行".to_string());
-    }
+        if instance_lock.is_none() {
+            return Err("服务未运行".to_string());
+        }
 
-    // 停止 Axum 服务器 (仅逻辑停止，不杀死进程)
-    if let Some(instance) = instance_lock.take() {
-        instance.token_manager.abort_background_tasks().await;
-        instance.axum_server.set_running(false).await;
-        // 已移除 instance.axum_server.stop() 调用，防止杀死 Admin Server
-    }
+        // 停止 Axum 服务器 (仅逻辑停止，不杀死进程)
+        if let Some(instance) = instance_lock.take() {
+            instance.token_manager.abort_background_tasks().await;
+            instance.axum_server.set_running(false).await;
+            // 已移除 instance.axum_server.stop() 调用，防止杀死 Admin Server
+        }
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 /// 获取反代服务状态
 #[tauri::command]
 pub async fn get_proxy_status(state: State<'_, ProxyServiceState>) -> Result<ProxyStatus, String> {
-    // 优先检查启动标志，避免被写锁阻塞
-    if state.starting.load(Ordering::SeqCst) {
-        return Ok(ProxyStatus {
-            running: false, // 逻辑上还没运行
-            port: 0,
-            base_url: "starting".to_string(), // 给前端标识
-            active_accounts: 0,
-        });
-    }
-
-    // 使用 try_read 避免在该命令中产生产生排队延迟
-    let lock_res = state.instance.try_read();
-
-    match lock_res {
-        Ok(instance_lock) => match instance_lock.as_ref() {
-            Some(instance) => Ok(ProxyStatus {
-                running: true,
-                port: instance.config.port,
-                base_url: format!("http://127.0.0.1:{}", instance.config.port),
-                active_accounts: instance.token_manager.len(),
-            }),
-            None => Ok(ProxyStatus {
-                running: false,
-                port: 0,
-                base_url: String::new(),
-                active_accounts: 0,
-            }),
-        },
-        Err(_) => {
-            // 如果拿不到锁，说明正在进行写操作（可能是正在启动或停止中）
-            Ok(ProxyStatus {
-                running: false,
+    crate::commands::last_error::track_command("get_proxy_status", async {
+        // 优先检查启动标志，避免被写锁阻塞
+        if state.starting.load(Ordering::SeqCst) {
+            return Ok(ProxyStatus {
+                running: false, // 逻辑上还没运行
                 port: 0,
-                base_url: "busy".to_string(),
+                base_url: "starting".to_string(), // 给前端标识
                 active_accounts: 0,
-            })
+            });
         }
-    }
+
+        // 使用 try_read 避免在该命令中产生产生排队延迟
+        let lock_res = state.instance.try_read();
+
+        match lock_res {
+            Ok(instance_lock) => match instance_lock.as_ref() {
+                Some(instance) => Ok(ProxyStatus {
+                    running: true,
+                    port: instance.config.port,
+                    base_url: format!("http://127.0.0.1:{}", instance.config.port),
+                    active_accounts: instance.token_manager.len(),
+                }),
+                None => Ok(ProxyStatus {
+                    running: false,
+                    port: 0,
+                    base_url: String::new(),
+                    active_accounts: 0,
+                }),
+            },
+            Err(_) => {
+                // 如果拿不到锁，说明正在进行写操作（可能是正在启动或停止中）
+                Ok(ProxyStatus {
+                    running: false,
+                    port: 0,
+                    base_url: "busy".to_string(),
+                    active_accounts: 0,
+                })
+            }
+        }
+    })
+    .await
 }
 
 /// 获取反代服务统计
 #[tauri::command]
 pub async fn get_proxy_stats(state: State<'_, ProxyServiceState>) -> Result<ProxyStats, String> {
-    let monitor_lock = state.monitor.read().await;
-    if let Some(monitor) = monitor_lock.as_ref() {
-        Ok(monitor.get_stats().await)
-    } else {
-        Ok(ProxyStats::default())
-    }
+    crate::commands::last_error::track_command("get_proxy_stats", async {
+        let monitor_lock = state.monitor.read().await;
+        if let Some(monitor) = monitor_lock.as_ref() {
+            Ok(monitor.get_stats().await)
+        } else {
+            Ok(ProxyStats::default())
+        }
+    })
+    .await
+}
+
+/// 导出内存中滚动窗口的延迟采样 (timestamp, model, duration_ms, ttfb_ms)
+#[tauri::command]
+pub async fn export_latency_samples(
+    state: State<'_, ProxyServiceState>,
+) -> Result<Vec<crate::proxy::monitor::LatencySample>, String> {
+    crate::commands::last_error::track_command("export_latency_samples", async {
+        let monitor_lock = state.monitor.read().await;
+        if let Some(monitor) = monitor_lock.as_ref() {
+            Ok(monitor.export_latency_samples().await)
+        } else {
+            Ok(Vec::new())
+        }
+    })
+    .await
 }
 
 /// 获取反代请求日志
@@ -357,12 +389,15 @@ pub async fn get_proxy_logs(
     state: State<'_, ProxyServiceState>,
     limit: Option<usize>,
 ) -> Result<Vec<ProxyRequestLog>, String> {
-    let monitor_lock = state.monitor.read().await;
-    if let Some(monitor) = monitor_lock.as_ref() {
-        Ok(monitor.get_logs(limit.unwrap_or(100)).await)
-    } else {
-        Ok(Vec::new())
-    }
+    crate::commands::last_error::track_command("get_proxy_logs", async {
+        let monitor_lock = state.monitor.read().await;
+        if let Some(monitor) = monitor_lock.as_ref() {
+            Ok(monitor.get_logs(limit.unwrap_or(100)).await)
+        } else {
+            Ok(Vec::new())
+        }
+    })
+    .await
 }
 
 /// 设置监控开启状态
@@ -371,21 +406,27 @@ pub async fn set_proxy_monitor_enabled(
     state: State<'_, ProxyServiceState>,
     enabled: bool,
 ) -> Result<(), String> {
-    let monitor_lock = state.monitor.read().await;
-    if let Some(monitor) = monitor_lock.as_ref() {
-        monitor.set_enabled(enabled);
-    }
-    Ok(())
+    crate::commands::last_error::track_command("set_proxy_monitor_enabled", async {
+        let monitor_lock = state.monitor.read().await;
+        if let Some(monitor) = monitor_lock.as_ref() {
+            monitor.set_enabled(enabled);
+        }
+        Ok(())
+    })
+    .await
 }
 
 /// 清除反代请求日志
 #[tauri::command]
 pub async fn clear_proxy_logs(state: State<'_, ProxyServiceState>) -> Result<(), String> {
-    let monitor_lock = state.monitor.read().await;
-    if let Some(monitor) = monitor_lock.as_ref() {
-        monitor.clear().await;
-    }
-    Ok(())
+    crate::commands::last_error::track_command("clear_proxy_logs", async {
+        let monitor_lock = state.monitor.read().await;
+        if let Some(monitor) = monitor_lock.as_ref() {
+            monitor.clear().await;
+        }
+        Ok(())
+    })
+    .await
 }
 
 /// 获取反代请求日志 (分页)
@@ -394,50 +435,88 @@ pub async fn get_proxy_logs_paginated(
     limit: Option<usize>,
     offset: Option<usize>,
 ) -> Result<Vec<ProxyRequestLog>, String> {
-    crate::modules::proxy_db::get_logs_summary(limit.unwrap_or(20), offset.unwrap_or(0))
+    crate::commands::last_error::track_command("get_proxy_logs_paginated", async {
+        crate::modules::proxy_db::get_logs_summary(limit.unwrap_or(20), offset.unwrap_or(0))
+    })
+    .await
 }
 
 /// 获取单条日志的完整详情
 #[tauri::command]
 pub async fn get_proxy_log_detail(log_id: String) -> Result<ProxyRequestLog, String> {
-    crate::modules::proxy_db::get_log_detail(&log_id)
+    crate::commands::last_error::track_command("get_proxy_log_detail", async {
+        crate::modules::proxy_db::get_log_detail(&log_id)
+    })
+    .await
 }
 
 /// 获取日志总数
 #[tauri::command]
 pub async fn get_proxy_logs_count() -> Result<u64, String> {
-    crate::modules::proxy_db::get_logs_count()
+    crate::commands::last_error::track_command("get_proxy_logs_count", async {
+        crate::modules::proxy_db::get_logs_count()
+    })
+    .await
 }
 
 /// 导出所有日志到指定文件
 #[tauri::command]
 pub async fn export_proxy_logs(file_path: String) -> Result<usize, String> {
-    let logs = crate::modules::proxy_db::get_all_logs_for_export()?;
-    let count = logs.len();
+    crate::commands::last_error::track_command("export_proxy_logs", async {
+        let logs = crate::modules::proxy_db::get_all_logs_for_export()?;
+        let count = logs.len();
 
-    let json = serde_json::to_string_pretty(&logs)
-        .map_err(|e| format!("Failed to serialize logs: {}", e))?;
+        let json = serde_json::to_string_pretty(&logs)
+            .map_err(|e| format!("Failed to serialize logs: {}", e))?;
 
-    std::fs::write(&file_path, json).map_err(|e| format!("Failed to write file: {}", e))?;
+        std::fs::write(&file_path, json).map_err(|e| format!("Failed to write file: {}", e))?;
 
-    Ok(count)
+        Ok(count)
+    })
+    .await
+}
+
+/// 将调试日志打包导出为 zip (API Key 已脱敏)，日期格式 YYYYMMDD
+#[tauri::command]
+pub async fn export_debug_logs_zip(
+    dest_path: String,
+    from_date: Option<String>,
+    to_date: Option<String>,
+) -> Result<crate::proxy::debug_logger::DebugLogExportSummary, String> {
+    crate::commands::last_error::track_command("export_debug_logs_zip", async {
+        let cfg = crate::modules::config::load_app_config()?.proxy.debug_logging;
+        tokio::task::spawn_blocking(move || {
+            crate::proxy::debug_logger::export_logs_zip(
+                &cfg,
+                std::path::Path::new(&dest_path),
+                from_date.as_deref(),
+                to_date.as_deref(),
+            )
+        })
+        .await
+        .map_err(|e| format!("Export task failed: {}", e))?
+    })
+    .await
 }
 
 /// 导出指定的日志JSON到文件
 #[tauri::command]
 pub async fn export_proxy_logs_json(file_path: String, json_data: String) -> Result<usize, String> {
-    // Parse to count items
-    let logs: Vec<serde_json::Value> =
-        serde_json::from_str(&json_data).map_err(|e| format!("Failed to parse JSON: {}", e))?;
-    let count = logs.len();
+    crate::commands::last_error::track_command("export_proxy_logs_json", async {
+        // Parse to count items
+        let logs: Vec<serde_json::Value> =
+            serde_json::from_str(&json_data).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+        let count = logs.len();
 
-    // Pretty print
-    let pretty_json =
-        serde_json::to_string_pretty(&logs).map_err(|e| format!("Failed to serialize: {}", e))?;
+        // Pretty print
+        let pretty_json =
+            serde_json::to_string_pretty(&logs).map_err(|e| format!("Failed to serialize: {}", e))?;
 
-    std::fs::write(&file_path, pretty_json).map_err(|e| format!("Failed to write file: {}", e))?;
+        std::fs::write(&file_path, pretty_json).map_err(|e| format!("Failed to write file: {}", e))?;
 
-    Ok(count)
+        Ok(count)
+    })
+    .await
 }
 
 /// 获取带搜索条件的日志数量
@@ -446,7 +525,10 @@ pub async fn get_proxy_logs_count_filtered(
     filter: String,
     errors_only: bool,
 ) -> Result<u64, String> {
-    crate::modules::proxy_db::get_logs_count_filtered(&filter, errors_only)
+    crate::commands::last_error::track_command("get_proxy_logs_count_filtered", async {
+        crate::modules::proxy_db::get_logs_count_filtered(&filter, errors_only)
+    })
+    .await
 }
 
 /// 获取带搜索条件的分页日志
@@ -457,7 +539,10 @@ pub async fn get_proxy_logs_filtered(
     limit: usize,
     offset: usize,
 ) -> Result<Vec<crate::proxy::monitor::ProxyRequestLog>, String> {
-    crate::modules::proxy_db::get_logs_filtered(&filter, errors_only, limit, offset)
+    crate::commands::last_error::track_command("get_proxy_logs_filtered", async {
+        crate::modules::proxy_db::get_logs_filtered(&filter, errors_only, limit, offset)
+    })
+    .await
 }
 
 /// 生成 API Key
@@ -469,24 +554,27 @@ pub fn generate_api_key() -> String {
 /// 重新加载账号（当主应用添加/删除账号时调用）
 #[tauri::command]
 pub async fn reload_proxy_accounts(state: State<'_, ProxyServiceState>) -> Result<usize, String> {
-    let instance_lock = state.instance.read().await;
-
-    if let Some(instance) = instance_lock.as_ref() {
-        // [FIX #820] Clear stale session bindings before reloading accounts
-        // This ensures that after switching accounts in the UI, API requests
-        // won't be routed to the previously bound (wrong) account
-        instance.token_manager.clear_all_sessions();
+    crate::commands::last_error::track_command("reload_proxy_accounts", async {
+        let instance_lock = state.instance.read().await;
 
-        // 重新加载账号
-        let count = instance
-            .token_manager
-            .load_accounts()
-            .await
-            .map_err(|e| format!("重新加载账号失败: {}", e))?;
-        Ok(count)
-    } else {
-        Err("服务未运行".to_string())
-    }
+        if let Some(instance) = instance_lock.as_ref() {
+            // [FIX #820] Clear stale session bindings before reloading accounts
+            // This ensures that after switching accounts in the UI, API requests
+            // won't be routed to the previously bound (wrong) account
+            instance.token_manager.clear_all_sessions();
+
+            // 重新加载账号
+            let count = instance
+                .token_manager
+                .load_accounts()
+                .await
+                .map_err(|e| format!("重新加载账号失败: {}", e))?;
+            Ok(count)
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
 }
 
 /// 更新模型映射表 (热更新)
@@ -495,21 +583,24 @@ pub async fn update_model_mapping(
     config: ProxyConfig,
     state: State<'_, ProxyServiceState>,
 ) -> Result<(), String> {
-    let instance_lock = state.instance.read().await;
+    crate::commands::last_error::track_command("update_model_mapping", async {
+        let instance_lock = state.instance.read().await;
 
-    // 1. 如果服务正在运行，立即更新内存中的映射 (这里目前只更新了 anthropic_mapping 的 RwLock,
-    // 后续可以根据需要让 resolve_model_route 直接读取全量 config)
-    if let Some(instance) = instance_lock.as_ref() {
-        instance.axum_server.update_mapping(&config).await;
-        tracing::debug!("后端服务已接收全量模型映射配置");
-    }
+        // 1. 如果服务正在运行，立即更新内存中的映射 (这里目前只更新了 anthropic_mapping 的 RwLock,
+        // 后续可以根据需要让 resolve_model_route 直接读取全量 config)
+        if let Some(instance) = instance_lock.as_ref() {
+            instance.axum_server.update_mapping(&config).await;
+            tracing::debug!("后端服务已接收全量模型映射配置");
+        }
 
-    // 2. 无论是否运行，都保存到全局配置持久化
-    let mut app_config = crate::modules::config::load_app_config().map_err(|e| e)?;
-    app_config.proxy.custom_mapping = config.custom_mapping;
-    crate::modules::config::save_app_config(&app_config).map_err(|e| e)?;
+        // 2. 无论是否运行，都保存到全局配置持久化
+        let mut app_config = crate::modules::config::load_app_config().map_err(|e| e)?;
+        app_config.proxy.custom_mapping = config.custom_mapping;
+        crate::modules::config::save_app_config(&app_config).map_err(|e| e)?;
 
-    Ok(())
+        Ok(())
+    })
+    .await
 }
 
 fn join_base_url(base: &str, path: &str) -> String {
@@ -577,58 +668,61 @@ pub async fn fetch_zai_models(
     upstream_proxy: crate::proxy::config::UpstreamProxyConfig,
     request_timeout: u64,
 ) -> Result<Vec<String>, String> {
-    if zai.base_url.trim().is_empty() {
-        return Err("z.ai base_url is empty".to_string());
-    }
-    if zai.api_key.trim().is_empty() {
-        return Err("z.ai api_key is not set".to_string());
-    }
+    crate::commands::last_error::track_command("fetch_zai_models", async {
+        if zai.base_url.trim().is_empty() {
+            return Err("z.ai base_url is empty".to_string());
+        }
+        if zai.api_key.trim().is_empty() {
+            return Err("z.ai api_key is not set".to_string());
+        }
 
-    let url = join_base_url(&zai.base_url, "/v1/models");
+        let url = join_base_url(&zai.base_url, "/v1/models");
 
-    let mut builder =
-        reqwest::Client::builder().timeout(Duration::from_secs(request_timeout.max(5)));
-    if upstream_proxy.enabled && !upstream_proxy.url.is_empty() {
-        let proxy = reqwest::Proxy::all(&upstream_proxy.url)
-            .map_err(|e| format!("Invalid upstream proxy url: {}", e))?;
-        builder = builder.proxy(proxy);
-    }
-    let client = builder
-        .build()
-        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
-
-    let resp = client
-        .get(&url)
-        .header("Authorization", format!("Bearer {}", zai.api_key))
-        .header("x-api-key", zai.api_key)
-        .header("anthropic-version", "2023-06-01")
-        .header("accept", "application/json")
-        .send()
-        .await
-        .map_err(|e| format!("Upstream request failed: {}", e))?;
-
-    let status = resp.status();
-    let text = resp
-        .text()
-        .await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
+        let mut builder =
+            reqwest::Client::builder().timeout(Duration::from_secs(request_timeout.max(5)));
+        if upstream_proxy.enabled && !upstream_proxy.url.is_empty() {
+            let proxy = reqwest::Proxy::all(&upstream_proxy.url)
+                .map_err(|e| format!("Invalid upstream proxy url: {}", e))?;
+            builder = builder.proxy(proxy);
+        }
+        let client = builder
+            .build()
+            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+        let resp = client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", zai.api_key))
+            .header("x-api-key", zai.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| format!("Upstream request failed: {}", e))?;
 
-    if !status.is_success() {
-        let preview = if text.len() > 4000 {
-            &text[..4000]
-        } else {
-            &text
-        };
-        return Err(format!("Upstream returned {}: {}", status, preview));
-    }
+        let status = resp.status();
+        let text = resp
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read response: {}", e))?;
+
+        if !status.is_success() {
+            let preview = if text.len() > 4000 {
+                &text[..4000]
+            } else {
+                &text
+            };
+            return Err(format!("Upstream returned {}: {}", status, preview));
+        }
 
-    let json: serde_json::Value =
-        serde_json::from_str(&text).map_err(|e| format!("Invalid JSON response: {}", e))?;
-    let mut models = extract_model_ids(&json);
-    models.retain(|s| !s.trim().is_empty());
-    models.sort();
-    models.dedup();
-    Ok(models)
+        let json: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| format!("Invalid JSON response: {}", e))?;
+        let mut models = extract_model_ids(&json);
+        models.retain(|s| !s.trim().is_empty());
+        models.sort();
+        models.dedup();
+        Ok(models)
+    })
+    .await
 }
 
 /// 获取当前调度配置
@@ -636,12 +730,15 @@ pub async fn fetch_zai_models(
 pub async fn get_proxy_scheduling_config(
     state: State<'_, ProxyServiceState>,
 ) -> Result<crate::proxy::sticky_config::StickySessionConfig, String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        Ok(instance.token_manager.get_sticky_config().await)
-    } else {
-        Ok(crate::proxy::sticky_config::StickySessionConfig::default())
-    }
+    crate::commands::last_error::track_command("get_proxy_scheduling_config", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            Ok(instance.token_manager.get_sticky_config().await)
+        } else {
+            Ok(crate::proxy::sticky_config::StickySessionConfig::default())
+        }
+    })
+    .await
 }
 
 /// 更新调度配置
@@ -650,13 +747,16 @@ pub async fn update_proxy_scheduling_config(
     state: State<'_, ProxyServiceState>,
     config: crate::proxy::sticky_config::StickySessionConfig,
 ) -> Result<(), String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        instance.token_manager.update_sticky_config(config).await;
-        Ok(())
-    } else {
-        Err("服务未运行，无法更新实时配置".to_string())
-    }
+    crate::commands::last_error::track_command("update_proxy_scheduling_config", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            instance.token_manager.update_sticky_config(config).await;
+            Ok(())
+        } else {
+            Err("服务未运行，无法更新实时配置".to_string())
+        }
+    })
+    .await
 }
 
 /// 清除所有会话粘性绑定
@@ -664,13 +764,16 @@ pub async fn update_proxy_scheduling_config(
 pub async fn clear_proxy_session_bindings(
     state: State<'_, ProxyServiceState>,
 ) -> Result<(), String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        instance.token_manager.clear_all_sessions();
-        Ok(())
-    } else {
-        Err("服务未运行".to_string())
-    }
+    crate::commands::last_error::track_command("clear_proxy_session_bindings", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            instance.token_manager.clear_all_sessions();
+            Ok(())
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
 }
 
 // ===== [FIX #820] 固定账号模式命令 =====
@@ -682,37 +785,40 @@ pub async fn set_preferred_account(
     state: State<'_, ProxyServiceState>,
     account_id: Option<String>,
 ) -> Result<(), String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        // 过滤空字符串为 None
-        let cleaned_id = account_id.filter(|s| !s.trim().is_empty());
-
-        // 1. 更新内存状态
-        instance
-            .token_manager
-            .set_preferred_account(cleaned_id.clone())
-            .await;
+    crate::commands::last_error::track_command("set_preferred_account", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            // 过滤空字符串为 None
+            let cleaned_id = account_id.filter(|s| !s.trim().is_empty());
+
+            // 1. 更新内存状态
+            instance
+                .token_manager
+                .set_preferred_account(cleaned_id.clone())
+                .await;
+
+            // 2. 持久化到配置文件 (修复 Issue #820 自动关闭问题)
+            let mut app_config = crate::modules::config::load_app_config()
+                .map_err(|e| format!("加载配置失败: {}", e))?;
+            app_config.proxy.preferred_account_id = cleaned_id.clone();
+            crate::modules::config::save_app_config(&app_config)
+                .map_err(|e| format!("保存配置失败: {}", e))?;
+
+            if let Some(ref id) = cleaned_id {
+                tracing::info!(
+                    "🔒 [FIX #820] Fixed account mode enabled and persisted: {}",
+                    id
+                );
+            } else {
+                tracing::info!("🔄 [FIX #820] Round-robin mode enabled and persisted");
+            }
 
-        // 2. 持久化到配置文件 (修复 Issue #820 自动关闭问题)
-        let mut app_config = crate::modules::config::load_app_config()
-            .map_err(|e| format!("加载配置失败: {}", e))?;
-        app_config.proxy.preferred_account_id = cleaned_id.clone();
-        crate::modules::config::save_app_config(&app_config)
-            .map_err(|e| format!("保存配置失败: {}", e))?;
-
-        if let Some(ref id) = cleaned_id {
-            tracing::info!(
-                "🔒 [FIX #820] Fixed account mode enabled and persisted: {}",
-                id
-            );
+            Ok(())
         } else {
-            tracing::info!("🔄 [FIX #820] Round-robin mode enabled and persisted");
+            Err("服务未运行".to_string())
         }
-
-        Ok(())
-    } else {
-        Err("服务未运行".to_string())
-    }
+    })
+    .await
 }
 
 /// 获取当前优先使用的账号ID
@@ -720,12 +826,15 @@ pub async fn set_preferred_account(
 pub async fn get_preferred_account(
     state: State<'_, ProxyServiceState>,
 ) -> Result<Option<String>, String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        Ok(instance.token_manager.get_preferred_account().await)
-    } else {
-        Ok(None)
-    }
+    crate::commands::last_error::track_command("get_preferred_account", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            Ok(instance.token_manager.get_preferred_account().await)
+        } else {
+            Ok(None)
+        }
+    })
+    .await
 }
 
 /// 清除指定账号的限流记录
@@ -734,12 +843,15 @@ pub async fn clear_proxy_rate_limit(
     state: State<'_, ProxyServiceState>,
     account_id: String,
 ) -> Result<bool, String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        Ok(instance.token_manager.clear_rate_limit(&account_id))
-    } else {
-        Err("服务未运行".to_string())
-    }
+    crate::commands::last_error::track_command("clear_proxy_rate_limit", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            Ok(instance.token_manager.clear_rate_limit(&account_id))
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
 }
 
 /// 清除所有限流记录
@@ -747,13 +859,69 @@ pub async fn clear_proxy_rate_limit(
 pub async fn clear_all_proxy_rate_limits(
     state: State<'_, ProxyServiceState>,
 ) -> Result<(), String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        instance.token_manager.clear_all_rate_limits();
+    crate::commands::last_error::track_command("clear_all_proxy_rate_limits", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            instance.token_manager.clear_all_rate_limits();
+            Ok(())
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
+}
+
+/// 更新调试日志配置 (保存并热更新运行中的服务)
+#[tauri::command]
+pub async fn update_debug_logging_config(
+    state: State<'_, ProxyServiceState>,
+    config: crate::proxy::config::DebugLoggingConfig,
+) -> Result<(), String> {
+    crate::commands::last_error::track_command("update_debug_logging_config", async {
+        let mut app_config = crate::modules::config::load_app_config()?;
+        app_config.proxy.debug_logging = config;
+        crate::modules::config::save_app_config(&app_config)?;
+
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            instance
+                .axum_server
+                .update_debug_logging(&app_config.proxy)
+                .await;
+        }
         Ok(())
-    } else {
-        Err("服务未运行".to_string())
-    }
+    })
+    .await
+}
+
+/// 获取限流参数
+#[tauri::command]
+pub async fn get_rate_limit_config(
+    state: State<'_, ProxyServiceState>,
+) -> Result<RateLimitConfig, String> {
+    crate::commands::last_error::track_command("get_rate_limit_config", async {
+        Ok(*state.rate_limit_config.read().await)
+    })
+    .await
+}
+
+/// 更新限流参数 (服务运行中时立即生效)
+#[tauri::command]
+pub async fn set_rate_limit_config(
+    state: State<'_, ProxyServiceState>,
+    config: RateLimitConfig,
+) -> Result<(), String> {
+    crate::commands::last_error::track_command("set_rate_limit_config", async {
+        config.validate()?;
+        *state.rate_limit_config.write().await = config;
+
+        let admin_lock = state.admin_server.read().await;
+        if let Some(admin) = admin_lock.as_ref() {
+            admin.axum_server.token_manager.update_rate_limit_config(config);
+        }
+        Ok(())
+    })
+    .await
 }
 
 /// 触发所有代理的健康检查，并返回更新后的配置
@@ -761,19 +929,22 @@ pub async fn clear_all_proxy_rate_limits(
 pub async fn check_proxy_health(
     state: State<'_, ProxyServiceState>,
 ) -> Result<ProxyPoolConfig, String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        let pool_state = instance.axum_server.proxy_pool_state.clone();
-        let manager = crate::proxy::proxy_pool::ProxyPoolManager::new(pool_state.clone());
+    crate::commands::last_error::track_command("check_proxy_health", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            let pool_state = instance.axum_server.proxy_pool_state.clone();
+            let manager = crate::proxy::proxy_pool::ProxyPoolManager::new(pool_state.clone());
 
-        manager.health_check().await?;
+            manager.health_check().await?;
 
-        // Return the updated config from memory
-        let config = pool_state.read().await;
-        Ok(config.clone())
-    } else {
-        Err("服务未运行".to_string())
-    }
+            // Return the updated config from memory
+            let config = pool_state.read().await;
+            Ok(config.clone())
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
 }
 
 /// 获取当前内存中的代理池状态
@@ -781,11 +952,14 @@ pub async fn check_proxy_health(
 pub async fn get_proxy_pool_config(
     state: State<'_, ProxyServiceState>,
 ) -> Result<ProxyPoolConfig, String> {
-    let instance_lock = state.instance.read().await;
-    if let Some(instance) = instance_lock.as_ref() {
-        let config = instance.axum_server.proxy_pool_state.read().await;
-        Ok(config.clone())
-    } else {
-        Err("服务未运行".to_string())
-    }
+    crate::commands::last_error::track_command("get_proxy_pool_config", async {
+        let instance_lock = state.instance.read().await;
+        if let Some(instance) = instance_lock.as_ref() {
+            let config = instance.axum_server.proxy_pool_state.read().await;
+            Ok(config.clone())
+        } else {
+            Err("服务未运行".to_string())
+        }
+    })
+    .await
 }
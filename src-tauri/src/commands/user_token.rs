@@ -27,53 +27,71 @@ pub struct UpdateTokenRequest {
 /// 列出所有令牌
 #[tauri::command]
 pub async fn list_user_tokens() -> Result<Vec<UserToken>, String> {
-    user_token_db::list_tokens()
+    crate::commands::last_error::track_command("list_user_tokens", async {
+        user_token_db::list_tokens()
+    })
+    .await
 }
 
 /// 创建新令牌
 #[tauri::command]
 pub async fn create_user_token(request: CreateTokenRequest) -> Result<UserToken, String> {
-    user_token_db::create_token(
-        request.username,
-        request.expires_type,
-        request.description,
-        request.max_ips,
-        request.curfew_start,
-        request.curfew_end,
-        request.custom_expires_at,
-    )
+    crate::commands::last_error::track_command("create_user_token", async {
+        user_token_db::create_token(
+            request.username,
+            request.expires_type,
+            request.description,
+            request.max_ips,
+            request.curfew_start,
+            request.curfew_end,
+            request.custom_expires_at,
+        )
+    })
+    .await
 }
 
 /// 更新令牌
 #[tauri::command]
 pub async fn update_user_token(id: String, request: UpdateTokenRequest) -> Result<(), String> {
-    user_token_db::update_token(
-        &id,
-        request.username,
-        request.description,
-        request.enabled,
-        request.max_ips,
-        request.curfew_start,
-        request.curfew_end,
-    )
+    crate::commands::last_error::track_command("update_user_token", async {
+        user_token_db::update_token(
+            &id,
+            request.username,
+            request.description,
+            request.enabled,
+            request.max_ips,
+            request.curfew_start,
+            request.curfew_end,
+        )
+    })
+    .await
 }
 
 /// 删除令牌
 #[tauri::command]
 pub async fn delete_user_token(id: String) -> Result<(), String> {
-    user_token_db::delete_token(&id)
+    crate::commands::last_error::track_command("delete_user_token", async {
+        user_token_db::delete_token(&id)
+    })
+    .await
 }
 
 /// 续期令牌
 #[tauri::command]
 pub async fn renew_user_token(id: String, expires_type: String) -> Result<(), String> {
-    user_token_db::renew_token(&id, &expires_type)
+    crate::commands::last_error::track_command("renew_user_token", async {
+        user_token_db::renew_token(&id, &expires_type)
+    })
+    .await
 }
 
 /// 获取令牌 IP 绑定
 #[tauri::command]
 pub async fn get_token_ip_bindings(token_id: String) -> Result<Vec<TokenIpBinding>, String> {
-    user_token_db::get_token_ips(&token_id)
+    crate::commands::last_error::track_command("get_token_ip_bindings", async {
+        user_token_db::get_token_ips(&token_id)
+    })
+    .await
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -87,22 +105,25 @@ pub struct UserTokenStats {
 /// 获取简单的统计信息
 #[tauri::command]
 pub async fn get_user_token_summary() -> Result<UserTokenStats, String> {
-    let tokens = user_token_db::list_tokens()?;
-    let active_tokens = tokens.iter().filter(|t| t.enabled).count();
-    
-    // 统计唯一用户
-    let mut users = std::collections::HashSet::new();
-    for t in &tokens {
-        users.insert(t.username.clone());
-    }
-    
-    // 这里简单返回一些数据，请求数最好从数据库聚合查询
-    // 目前仅作为演示，请求数暂不精确统计今日的
-    
-    Ok(UserTokenStats {
-        total_tokens: tokens.len(),
-        active_tokens,
-        total_users: users.len(),
-        today_requests: 0, // TODO: Implement daily stats query
+    crate::commands::last_error::track_command("get_user_token_summary", async {
+        let tokens = user_token_db::list_tokens()?;
+        let active_tokens = tokens.iter().filter(|t| t.enabled).count();
+        
+        // 统计唯一用户
+        let mut users = std::collections::HashSet::new();
+        for t in &tokens {
+            users.insert(t.username.clone());
+        }
+        
+        // 这里简单返回一些数据，请求数最好从数据库聚合查询
+        // 目前仅作为演示，请求数暂不精确统计今日的
+        
+        Ok(UserTokenStats {
+            total_tokens: tokens.len(),
+            active_tokens,
+            total_users: users.len(),
+            today_requests: 0, // TODO: Implement daily stats query
+        })
     })
+    .await
 }
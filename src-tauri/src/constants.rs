@@ -10,23 +10,51 @@ const CHANGELOG_URL: &str = "https://antigravity.google/changelog";
 /// Fallback version derived from Cargo.toml at compile time
 const FALLBACK_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Set to `1` to skip the remote version fetch (metered/offline connections)
+const OFFLINE_ENV: &str = "ANTIGRAVITY_OFFLINE";
+
+pub(crate) fn offline_mode_enabled() -> bool {
+    is_offline_value(std::env::var(OFFLINE_ENV).ok().as_deref())
+}
+
+fn is_offline_value(value: Option<&str>) -> bool {
+    value.map_or(false, |v| v.trim() == "1")
+}
+
 /// Pre-compiled regex for version parsing (X.Y.Z pattern)
 static VERSION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"\d+\.\d+\.\d+").expect("Invalid version regex")
 });
 
+/// Anchored variant of `VERSION_REGEX`: an optional `v` prefix, no word character or `.`
+/// on either side, and not followed by `.<digit>` (so IP addresses do not match)
+static VERSION_REGEX_STRICT: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\w.])v?(\d+\.\d+\.\d+)(?:$|[^\w.]|\.(?:$|\D))").expect("Invalid strict version regex")
+});
+
 /// Parse version from response text using pre-compiled regex
 /// Matches semver pattern: X.Y.Z (e.g., "1.15.8")
-fn parse_version(text: &str) -> Option<String> {
+pub fn parse_version(text: &str) -> Option<String> {
     VERSION_REGEX.find(text).map(|m| m.as_str().to_string())
 }
 
+/// Like `parse_version`, but the X.Y.Z must stand on its own: "v1.2.3" yields "1.2.3",
+/// while "a1.2.3b" or an IP address like "192.168.1.10" do not match
+pub fn parse_version_strict(text: &str) -> Option<String> {
+    VERSION_REGEX_STRICT
+        .captures(text)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
 /// Version source for logging
 #[derive(Debug, PartialEq)]
 enum VersionSource {
     RemoteAPI,
     ChangelogWeb,
     CargoToml,
+    /// Cargo.toml version because `ANTIGRAVITY_OFFLINE=1` skipped the network
+    Offline,
 }
 
 /// Fetch version from remote API or Changelog website
@@ -65,7 +93,7 @@ fn try_fetch_version(url: &'static str, thread_name: &str) -> Option<String> {
                 &text
             };
             
-            parse_version(scan_text)
+            parse_version_strict(scan_text)
         });
 
     match handle {
@@ -79,10 +107,14 @@ fn try_fetch_version(url: &'static str, thread_name: &str) -> Option<String> {
 
 /// Shared User-Agent string for all upstream API requests.
 /// Format: antigravity/{version} {os}/{arch}
-/// Version priority: remote endpoint > Cargo.toml
+/// Version priority: remote endpoint > Cargo.toml; with `ANTIGRAVITY_OFFLINE=1` only Cargo.toml
 /// OS and architecture are detected at runtime.
 pub static USER_AGENT: LazyLock<String> = LazyLock::new(|| {
-    let (version, source) = fetch_remote_version();
+    let (version, source) = if offline_mode_enabled() {
+        (FALLBACK_VERSION.to_string(), VersionSource::Offline)
+    } else {
+        fetch_remote_version()
+    };
 
     tracing::info!(
         version = %version,
@@ -122,6 +154,41 @@ mod tests {
         assert_eq!(parse_version("1.2"), None); // Only X.Y, not X.Y.Z
     }
 
+    #[test]
+    fn test_parse_version_strict() {
+        let text = "Auto updater is running. Stable Version: 1.15.8-5724687216017408";
+        assert_eq!(parse_version_strict(text), Some("1.15.8".to_string()));
+        assert_eq!(parse_version_strict("opencode/3.0.0-beta.1"), Some("3.0.0".to_string()));
+        assert_eq!(parse_version_strict("build12.3.4"), None);
+        assert_eq!(parse_version("build12.3.4"), Some("12.3.4".to_string()));
+        assert_eq!(parse_version_strict("1.2.3x"), None);
+    }
+
+    #[test]
+    fn test_parse_version_strict_prefix_and_ip() {
+        assert_eq!(parse_version_strict("v1.2.3"), Some("1.2.3".to_string()));
+        assert_eq!(parse_version_strict("opencode v0.15.2 (stable)"), Some("0.15.2".to_string()));
+        assert_eq!(parse_version_strict("Released 2.0.1."), Some("2.0.1".to_string()));
+        assert_eq!(parse_version_strict("listening on 192.168.1.10"), None);
+        assert_eq!(parse_version_strict("10.0.0.1 then 1.4.0"), Some("1.4.0".to_string()));
+    }
+
+    #[test]
+    fn test_is_offline_value() {
+        assert!(is_offline_value(Some("1")));
+        assert!(is_offline_value(Some(" 1 ")));
+        assert!(!is_offline_value(Some("0")));
+        assert!(!is_offline_value(Some("true")));
+        assert!(!is_offline_value(None));
+    }
+
+    #[test]
+    fn fallback_version_matches_cargo() {
+        // Guards against FALLBACK_VERSION being hardcoded again and drifting from Cargo.toml
+        assert_eq!(FALLBACK_VERSION, env!("CARGO_PKG_VERSION"));
+        assert!(parse_version(FALLBACK_VERSION).is_some());
+    }
+
     #[test]
     fn test_parse_version_with_suffix() {
         // Regex only matches X.Y.Z, suffix is naturally excluded
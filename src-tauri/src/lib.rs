@@ -71,6 +71,18 @@ pub fn run() {
         error!("Failed to initialize user token database: {}", e);
     }
 
+    // 可选: 启动时清理 OpenCode 配置目录中中断写入遗留的临时文件
+    let clean_opencode_temp = std::env::var("ABV_CLEAN_OPENCODE_TEMP")
+        .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false);
+    if clean_opencode_temp {
+        match proxy::opencode_sync::remove_stale_temp_files() {
+            Ok(removed) if !removed.is_empty() => info!("Removed stale OpenCode temp files: {:?}", removed),
+            Ok(_) => {}
+            Err(e) => warn!("Failed to clean stale OpenCode temp files: {}", e),
+        }
+    }
+
     if is_headless {
         info!("Starting in HEADLESS mode...");
 
@@ -377,6 +389,7 @@ pub fn run() {
             commands::get_antigravity_cache_paths,
             commands::open_data_folder,
             commands::get_data_dir_path,
+            commands::get_app_data_dir,
             commands::show_main_window,
             commands::set_window_theme,
             commands::get_antigravity_path,
@@ -400,6 +413,7 @@ pub fn run() {
             commands::proxy::get_proxy_logs_count,
             commands::proxy::export_proxy_logs,
             commands::proxy::export_proxy_logs_json,
+            commands::proxy::export_debug_logs_zip,
             commands::proxy::get_proxy_logs_count_filtered,
             commands::proxy::get_proxy_logs_filtered,
             commands::proxy::set_proxy_monitor_enabled,
@@ -417,6 +431,10 @@ pub fn run() {
             commands::proxy::get_preferred_account,
             commands::proxy::clear_proxy_rate_limit,
             commands::proxy::clear_all_proxy_rate_limits,
+            commands::proxy::get_rate_limit_config,
+            commands::proxy::set_rate_limit_config,
+            commands::proxy::update_debug_logging_config,
+            commands::proxy::export_latency_samples,
             commands::proxy::check_proxy_health,
             // Proxy Pool Binding commands
             commands::proxy_pool::bind_account_proxy,
@@ -426,6 +444,8 @@ pub fn run() {
             // Autostart commands
             commands::autostart::toggle_auto_launch,
             commands::autostart::is_auto_launch_enabled,
+            commands::last_error::get_last_error,
+            commands::last_error::clear_last_error,
             // Warmup commands
             commands::warm_up_all_accounts,
             commands::warm_up_account,
@@ -450,9 +470,55 @@ pub fn run() {
             proxy::cli_sync::get_cli_config_content,
             proxy::opencode_sync::get_opencode_sync_status,
             proxy::opencode_sync::execute_opencode_sync,
+            proxy::opencode_sync::execute_opencode_sync_new_models,
             proxy::opencode_sync::execute_opencode_restore,
             proxy::opencode_sync::get_opencode_config_content,
             proxy::opencode_sync::execute_opencode_clear,
+            proxy::opencode_sync::get_opencode_config_keys,
+            proxy::opencode_sync::set_account_tags,
+            proxy::opencode_sync::get_account_fingerprint_history,
+            proxy::opencode_sync::get_account_current_fingerprint,
+            proxy::opencode_sync::get_account_cooldowns,
+            proxy::opencode_sync::validate_opencode_json,
+            proxy::opencode_sync::get_opencode_installed_details,
+            proxy::opencode_sync::force_account_active_index,
+            proxy::opencode_sync::get_image_output_models,
+            proxy::opencode_sync::get_multimodal_models,
+            proxy::opencode_sync::create_opencode_config_snapshot,
+            proxy::opencode_sync::list_snapshots,
+            proxy::opencode_sync::restore_snapshot,
+            proxy::opencode_sync::import_accounts_from_opencode,
+            proxy::opencode_sync::generate_reproducer_config,
+            proxy::opencode_sync::validate_proxy_api_key,
+            proxy::opencode_sync::get_opencode_base_url,
+            proxy::opencode_sync::preview_accounts_sync_diff,
+            proxy::opencode_sync::get_model_catalog_changelog,
+            proxy::opencode_sync::reformat_opencode_config,
+            proxy::opencode_sync::benchmark_sync_performance,
+            proxy::opencode_sync::clean_stale_temp_files,
+            proxy::opencode_sync::reset_opencode_config_to_defaults,
+            proxy::opencode_sync::get_antigravity_provider_options,
+            proxy::opencode_sync::fix_opencode_reasoning_flags,
+            proxy::opencode_sync::backup_all_to,
+            proxy::opencode_sync::list_opencode_installs,
+            proxy::opencode_sync::set_preferred_opencode_install,
+            proxy::opencode_sync::get_accounts_file_schema,
+            proxy::opencode_sync::get_config_directory_contents,
+            proxy::opencode_sync::restore_from_file,
+            proxy::opencode_sync::enable_account,
+            proxy::opencode_sync::disable_account,
+            proxy::opencode_sync::get_model_variants,
+            proxy::opencode_sync::get_opencode_npm_package_info,
+            proxy::opencode_sync::preview_account_sync_state,
+            proxy::opencode_sync::get_antigravity_model_ids,
+            proxy::opencode_sync::start_opencode_autosync,
+            proxy::opencode_sync::stop_opencode_autosync,
+            proxy::opencode_sync::get_account_rate_limits,
+            proxy::opencode_sync::get_account_sync_summary,
+            proxy::opencode_sync::check_proxy_models,
+            proxy::opencode_sync::find_orphaned_backups,
+            proxy::opencode_sync::register_project_config_dir,
+            proxy::opencode_sync::unregister_project_config_dir,
             proxy::droid_sync::get_droid_sync_status,
             proxy::droid_sync::execute_droid_sync,
             proxy::droid_sync::execute_droid_restore,
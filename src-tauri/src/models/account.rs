@@ -57,6 +57,9 @@ pub struct Account {
     /// 用户自定义标签
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_label: Option<String>,
+    /// 允许使用的模型家族 (如 "claude" / "gemini")，None = 不限制
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_families: Option<Vec<String>>,
 }
 
 impl Account {
@@ -85,6 +88,7 @@ impl Account {
             proxy_id: None,
             proxy_bound_at: None,
             custom_label: None,
+            allowed_families: None,
         }
     }
 
@@ -371,12 +371,33 @@ fn default_false() -> bool {
     false
 }
 
+/// 调试日志输出格式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputFormat {
+    /// 每条日志一个格式化 JSON 文件
+    #[default]
+    PrettyJson,
+    /// 每条日志一个单行 JSON 文件
+    CompactJson,
+    /// 追加到按天滚动的 .ndjson 文件, 便于 Loki / Elasticsearch 采集
+    Ndjson,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugLoggingConfig {
     #[serde(default)]
     pub enabled: bool,
     #[serde(default)]
     pub output_dir: Option<String>,
+    /// 仅记录这些 trace ID 的调试日志 (为空表示全部记录)
+    #[serde(default)]
+    pub filter_trace_ids: Vec<String>,
+    /// 流式响应最多捕获的字节数, 超出后停止累积并标记 truncated (0 表示不限制)
+    #[serde(default = "default_max_capture_bytes")]
+    pub max_capture_bytes: usize,
+    #[serde(default)]
+    pub output_format: OutputFormat,
 }
 
 impl Default for DebugLoggingConfig {
@@ -384,10 +405,25 @@ impl Default for DebugLoggingConfig {
         Self {
             enabled: false,
             output_dir: None,
+            filter_trace_ids: Vec::new(),
+            max_capture_bytes: default_max_capture_bytes(),
+            output_format: OutputFormat::default(),
         }
     }
 }
 
+fn default_max_capture_bytes() -> usize {
+    64 * 1024 * 1024
+}
+
+impl DebugLoggingConfig {
+    /// 是否应记录该 trace 的调试日志
+    pub fn should_log_trace(&self, trace_id: Option<&str>) -> bool {
+        self.filter_trace_ids.is_empty()
+            || trace_id.map_or(false, |tid| self.filter_trace_ids.iter().any(|f| f == tid))
+    }
+}
+
 /// IP 黑名单配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IpBlacklistConfig {
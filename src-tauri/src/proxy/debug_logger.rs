@@ -1,9 +1,14 @@
+use serde::Serialize;
 use serde_json::Value;
 use tokio::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 use futures::StreamExt;
+use regex::Regex;
+use tracing::Instrument;
 
-use crate::proxy::config::DebugLoggingConfig;
+use crate::proxy::config::{DebugLoggingConfig, OutputFormat};
 
 fn build_filename(prefix: &str, trace_id: Option<&str>) -> String {
     let ts = chrono::Utc::now().format("%Y%m%d_%H%M%S%.3f");
@@ -11,6 +16,24 @@ fn build_filename(prefix: &str, trace_id: Option<&str>) -> String {
     format!("{}_{}_{}.json", ts, tid, prefix)
 }
 
+/// NDJSON 按天滚动; 文件名以 `YYYYMMDD_` 开头, 与导出的日期过滤一致
+fn build_ndjson_filename() -> String {
+    format!("{}_debug.ndjson", chrono::Utc::now().format("%Y%m%d"))
+}
+
+/// NDJSON 单行记录: 单文件模式下由文件名携带的信息放入字段
+fn build_ndjson_line(trace_id: Option<&str>, prefix: &str, payload: &Value) -> serde_json::Result<Vec<u8>> {
+    let record = serde_json::json!({
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+        "trace_id": trace_id.unwrap_or("unknown"),
+        "prefix": prefix,
+        "payload": payload,
+    });
+    let mut line = serde_json::to_vec(&record)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
 fn resolve_output_dir(cfg: &DebugLoggingConfig) -> Option<PathBuf> {
     if let Some(dir) = cfg.output_dir.as_ref() {
         return Some(PathBuf::from(dir));
@@ -27,10 +50,31 @@ pub async fn write_debug_payload(
     prefix: &str,
     payload: &Value,
 ) {
-    if !cfg.enabled {
+    if !cfg.enabled || !cfg.should_log_trace(trace_id) {
         return;
     }
 
+    // 写入过程挂在 span 上，文件路径与耗时作为字段供 tracing subscriber 记录
+    let span = tracing::info_span!(
+        "debug_log_write",
+        trace_id = trace_id.unwrap_or("unknown"),
+        prefix,
+        path = tracing::field::Empty,
+        duration_ms = tracing::field::Empty,
+    );
+    let started = std::time::Instant::now();
+    write_payload_file(cfg, trace_id, prefix, payload)
+        .instrument(span.clone())
+        .await;
+    span.record("duration_ms", started.elapsed().as_millis() as u64);
+}
+
+async fn write_payload_file(
+    cfg: &DebugLoggingConfig,
+    trace_id: Option<&str>,
+    prefix: &str,
+    payload: &Value,
+) {
     let output_dir = match resolve_output_dir(cfg) {
         Some(dir) => dir,
         None => {
@@ -44,10 +88,21 @@ pub async fn write_debug_payload(
         return;
     }
 
+    if cfg.output_format == OutputFormat::Ndjson {
+        append_ndjson_line(&output_dir, trace_id, prefix, payload).await;
+        return;
+    }
+
     let filename = build_filename(prefix, trace_id);
     let path = output_dir.join(filename);
+    tracing::Span::current().record("path", tracing::field::display(path.display()));
 
-    match serde_json::to_vec_pretty(payload) {
+    let serialized = if cfg.output_format == OutputFormat::CompactJson {
+        serde_json::to_vec(payload)
+    } else {
+        serde_json::to_vec_pretty(payload)
+    };
+    match serialized {
         Ok(bytes) => {
             if let Err(e) = fs::write(&path, bytes).await {
                 tracing::warn!("[Debug-Log] Failed to write file: {}", e);
@@ -59,10 +114,145 @@ pub async fn write_debug_payload(
     }
 }
 
+async fn append_ndjson_line(output_dir: &Path, trace_id: Option<&str>, prefix: &str, payload: &Value) {
+    use tokio::io::AsyncWriteExt;
+
+    let path = output_dir.join(build_ndjson_filename());
+    tracing::Span::current().record("path", tracing::field::display(path.display()));
+
+    let line = match build_ndjson_line(trace_id, prefix, payload) {
+        Ok(line) => line,
+        Err(e) => {
+            tracing::warn!("[Debug-Log] Failed to serialize payload: {}", e);
+            return;
+        }
+    };
+    // 追加模式整行写入, 多个请求共享同一文件
+    let result = async {
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(&path).await?;
+        file.write_all(&line).await
+    }
+    .await;
+    if let Err(e) = result {
+        tracing::warn!("[Debug-Log] Failed to append ndjson file: {}", e);
+    }
+}
+
+/// 生成用于关联调试日志条目的 trace ID
+pub fn generate_trace_id() -> String {
+    let id = uuid::Uuid::new_v4().simple().to_string();
+    format!("trace_{}", &id[..12])
+}
+
 pub fn is_enabled(cfg: &DebugLoggingConfig) -> bool {
     cfg.enabled
 }
 
+/// JSON 字段形式的密钥: "apiKey": "...", "authorization": "...", "x-api-key": "..."
+static SECRET_FIELD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?i)("(?:api[_-]?key|x-api-key|x-goog-api-key|authorization|refresh_?token|access_?token)"\s*:\s*")[^"]*(")"#)
+        .expect("Invalid secret field regex")
+});
+
+/// 裸露的 sk- 风格密钥
+static SK_KEY_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bsk-[A-Za-z0-9_\-]{8,}").expect("Invalid sk key regex")
+});
+
+/// Bearer token
+static BEARER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(bearer\s+)[A-Za-z0-9._~+/\-]+=*").expect("Invalid bearer regex")
+});
+
+/// 对调试日志内容中的 API Key / Token 进行脱敏
+pub fn redact_secrets(text: &str) -> String {
+    let redacted = SECRET_FIELD_REGEX.replace_all(text, "${1}[REDACTED]${2}");
+    let redacted = SK_KEY_REGEX.replace_all(&redacted, "sk-[REDACTED]");
+    BEARER_REGEX.replace_all(&redacted, "${1}[REDACTED]").into_owned()
+}
+
+/// 调试日志打包导出结果
+#[derive(Debug, Clone, Serialize)]
+pub struct DebugLogExportSummary {
+    pub file_count: usize,
+    pub total_bytes: u64,
+}
+
+/// 日志文件名以 `YYYYMMDD_` 开头，按日期 (含边界) 过滤
+fn file_in_date_range(file_name: &str, from_date: Option<&str>, to_date: Option<&str>) -> bool {
+    let Some(date) = file_name.get(..8) else {
+        return false;
+    };
+    if let Some(from) = from_date {
+        if date < from {
+            return false;
+        }
+    }
+    if let Some(to) = to_date {
+        if date > to {
+            return false;
+        }
+    }
+    true
+}
+
+/// 将 output_dir 下的调试日志 (可选日期过滤, YYYYMMDD) 脱敏后打包为单个 zip
+pub fn export_logs_zip(
+    cfg: &DebugLoggingConfig,
+    dest_path: &Path,
+    from_date: Option<&str>,
+    to_date: Option<&str>,
+) -> Result<DebugLogExportSummary, String> {
+    let output_dir = resolve_output_dir(cfg)
+        .ok_or_else(|| "Debug log output_dir is not available".to_string())?;
+    if !output_dir.exists() {
+        return Err(format!("Debug log directory does not exist: {:?}", output_dir));
+    }
+
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(&output_dir)
+        .map_err(|e| format!("Failed to read debug log directory: {}", e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            path.file_name()
+                .map(|n| file_in_date_range(&n.to_string_lossy(), from_date, to_date))
+                .unwrap_or(false)
+        })
+        .collect();
+    entries.sort();
+
+    let file = std::fs::File::create(dest_path)
+        .map_err(|e| format!("Failed to create zip file: {}", e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Stored);
+
+    let mut summary = DebugLogExportSummary { file_count: 0, total_bytes: 0 };
+    for path in entries {
+        let raw = match std::fs::read(&path) {
+            Ok(raw) => raw,
+            Err(e) => {
+                tracing::warn!("[Debug-Log] Skipping unreadable file {:?}: {}", path, e);
+                continue;
+            }
+        };
+        let redacted = redact_secrets(&String::from_utf8_lossy(&raw));
+        let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+
+        zip.start_file(name, options)
+            .map_err(|e| format!("Failed to add file to zip: {}", e))?;
+        zip.write_all(redacted.as_bytes())
+            .map_err(|e| format!("Failed to write zip entry: {}", e))?;
+
+        summary.file_count += 1;
+        summary.total_bytes += redacted.len() as u64;
+    }
+
+    zip.finish().map_err(|e| format!("Failed to finalize zip: {}", e))?;
+    Ok(summary)
+}
+
 /// 解析 SSE 流式数据，提取 thinking 和正文内容
 fn parse_sse_stream(raw: &str) -> (String, String) {
     let mut thinking_parts: Vec<String> = Vec::new();
@@ -127,6 +317,39 @@ fn parse_sse_stream(raw: &str) -> (String, String) {
     (thinking_parts.join(""), content_parts.join(""))
 }
 
+/// 流式响应的调试捕获缓冲区: 超过上限后只计数不再累积 (limit 为 0 表示不限制)
+struct CaptureBuffer {
+    collected: Vec<u8>,
+    limit: usize,
+    total_bytes: u64,
+    truncated: bool,
+}
+
+impl CaptureBuffer {
+    fn new(limit: usize) -> Self {
+        Self {
+            collected: Vec::new(),
+            limit,
+            total_bytes: 0,
+            truncated: false,
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        self.total_bytes += bytes.len() as u64;
+        if self.truncated {
+            return;
+        }
+        if self.limit == 0 || self.collected.len() + bytes.len() <= self.limit {
+            self.collected.extend_from_slice(bytes);
+            return;
+        }
+        let remaining = self.limit - self.collected.len();
+        self.collected.extend_from_slice(&bytes[..remaining]);
+        self.truncated = true;
+    }
+}
+
 pub fn wrap_reqwest_stream_with_debug(
     stream: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes, reqwest::Error>> + Send>>,
     cfg: DebugLoggingConfig,
@@ -139,16 +362,16 @@ pub fn wrap_reqwest_stream_with_debug(
     }
 
     let wrapped = async_stream::stream! {
-        let mut collected: Vec<u8> = Vec::new();
+        let mut capture = CaptureBuffer::new(cfg.max_capture_bytes);
         let mut inner = stream;
         while let Some(item) = inner.next().await {
             if let Ok(bytes) = &item {
-                collected.extend_from_slice(bytes);
+                capture.push(bytes);
             }
             yield item;
         }
 
-        let raw_text = String::from_utf8_lossy(&collected).to_string();
+        let raw_text = String::from_utf8_lossy(&capture.collected).to_string();
         let (thinking_content, response_content) = parse_sse_stream(&raw_text);
         
         let mut payload = serde_json::json!({
@@ -164,9 +387,96 @@ pub fn wrap_reqwest_stream_with_debug(
         if !response_content.is_empty() {
             payload["response_content"] = serde_json::Value::String(response_content);
         }
+        if capture.truncated {
+            payload["truncated"] = serde_json::Value::Bool(true);
+            payload["total_bytes"] = serde_json::json!(capture.total_bytes);
+        }
 
         write_debug_payload(&cfg, Some(&payload["trace_id"].as_str().unwrap_or("unknown")), prefix, &payload).await;
     };
 
     Box::pin(wrapped)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_log_trace_filter() {
+        let mut cfg = DebugLoggingConfig::default();
+        assert!(cfg.should_log_trace(Some("req_1")));
+        assert!(cfg.should_log_trace(None));
+
+        cfg.filter_trace_ids = vec!["req_1".to_string()];
+        assert!(cfg.should_log_trace(Some("req_1")));
+        assert!(!cfg.should_log_trace(Some("req_2")));
+        assert!(!cfg.should_log_trace(None));
+    }
+
+    #[test]
+    fn test_capture_buffer_truncates_at_limit() {
+        let mut capture = CaptureBuffer::new(8);
+        capture.push(b"12345");
+        capture.push(b"67890");
+        capture.push(b"abc");
+        assert_eq!(capture.collected, b"12345678");
+        assert!(capture.truncated);
+        assert_eq!(capture.total_bytes, 13);
+
+        let mut unlimited = CaptureBuffer::new(0);
+        unlimited.push(b"12345");
+        unlimited.push(b"67890");
+        assert_eq!(unlimited.collected.len(), 10);
+        assert!(!unlimited.truncated);
+    }
+
+    #[test]
+    fn test_build_ndjson_line_is_single_line() {
+        let payload = serde_json::json!({"kind": "request", "body": {"text": "a\nb"}});
+        let line = build_ndjson_line(Some("trace_1"), "req", &payload).unwrap();
+        let text = String::from_utf8(line).unwrap();
+        assert!(text.ends_with('\n'));
+        assert_eq!(text.matches('\n').count(), 1);
+
+        let record: Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(record["trace_id"], "trace_1");
+        assert_eq!(record["prefix"], "req");
+        assert_eq!(record["payload"], payload);
+        assert!(file_in_date_range(&build_ndjson_filename(), None, None));
+    }
+
+    #[test]
+    fn test_generate_trace_id_unique() {
+        let a = generate_trace_id();
+        let b = generate_trace_id();
+        assert!(a.starts_with("trace_"));
+        assert_eq!(a.len(), "trace_".len() + 12);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_redact_secrets_json_fields() {
+        let text = r#"{"apiKey": "abc123", "x-api-key":"zzz", "model": "gemini"}"#;
+        let redacted = redact_secrets(text);
+        assert!(!redacted.contains("abc123"));
+        assert!(!redacted.contains("zzz"));
+        assert!(redacted.contains(r#""model": "gemini""#));
+    }
+
+    #[test]
+    fn test_redact_secrets_bare_tokens() {
+        let redacted = redact_secrets("Authorization: Bearer eyJhbGciOi.abc-def and sk-1234567890abcdef");
+        assert!(!redacted.contains("eyJhbGciOi"));
+        assert!(!redacted.contains("1234567890abcdef"));
+    }
+
+    #[test]
+    fn test_file_in_date_range() {
+        let name = "20260115_120000.000_abc_original_request.json";
+        assert!(file_in_date_range(name, None, None));
+        assert!(file_in_date_range(name, Some("20260115"), Some("20260115")));
+        assert!(!file_in_date_range(name, Some("20260116"), None));
+        assert!(!file_in_date_range(name, None, Some("20260114")));
+    }
+}
@@ -303,37 +303,49 @@ pub fn read_droid_config_content() -> Result<String, String> {
 
 #[tauri::command]
 pub async fn get_droid_sync_status(proxy_url: String) -> Result<DroidStatus, String> {
-    let (installed, version) = check_droid_installed();
-    let (is_synced, has_backup, current_base_url, synced_count) = if installed {
-        get_sync_status(&proxy_url)
-    } else {
-        (false, false, None, 0)
-    };
-
-    Ok(DroidStatus {
-        installed,
-        version,
-        is_synced,
-        has_backup,
-        current_base_url,
-        files: vec![DROID_CONFIG_FILE.to_string()],
-        synced_count,
+    crate::commands::last_error::track_command("get_droid_sync_status", async {
+        let (installed, version) = check_droid_installed();
+        let (is_synced, has_backup, current_base_url, synced_count) = if installed {
+            get_sync_status(&proxy_url)
+        } else {
+            (false, false, None, 0)
+        };
+
+        Ok(DroidStatus {
+            installed,
+            version,
+            is_synced,
+            has_backup,
+            current_base_url,
+            files: vec![DROID_CONFIG_FILE.to_string()],
+            synced_count,
+        })
     })
+    .await
 }
 
 #[tauri::command]
 pub async fn execute_droid_sync(
     custom_models: Vec<Value>,
 ) -> Result<usize, String> {
-    sync_droid_config(custom_models)
+    crate::commands::last_error::track_command("execute_droid_sync", async {
+        sync_droid_config(custom_models)
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn execute_droid_restore() -> Result<(), String> {
-    restore_droid_config()
+    crate::commands::last_error::track_command("execute_droid_restore", async {
+        restore_droid_config()
+    })
+    .await
 }
 
 #[tauri::command]
 pub async fn get_droid_config_content() -> Result<String, String> {
-    read_droid_config_content()
+    crate::commands::last_error::track_command("get_droid_config_content", async {
+        read_droid_config_content()
+    })
+    .await
 }
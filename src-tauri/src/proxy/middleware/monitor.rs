@@ -176,6 +176,10 @@ pub async fn monitor_middleware(
     };
 
 
+    if !content_type.contains("text/event-stream") {
+        monitor.record_latency(log.model.clone(), duration, Some(duration)).await;
+    }
+
     if content_type.contains("text/event-stream") {
         let (parts, body) = response.into_parts();
         let mut stream = body.into_data_stream();
@@ -202,6 +206,11 @@ pub async fn monitor_middleware(
                     let _ = tx.send(Err(axum::Error::new(e))).await;
                 }
             }
+
+            // 流式请求: duration 为响应头返回时间 (TTFB)，完整耗时在流结束时计算
+            monitor
+                .record_latency(log.model.clone(), start.elapsed().as_millis() as u64, Some(log.duration))
+                .await;
             
             // Parse and consolidate stream data into readable format
             if let Ok(full_response) = std::str::from_utf8(&all_stream_data) {
@@ -32,8 +32,23 @@ pub struct ProxyStats {
     pub error_count: u64,
 }
 
+/// 延迟采样滚动窗口大小
+const LATENCY_WINDOW_SIZE: usize = 2000;
+
+/// 单次请求的延迟采样 (用于离线分析延迟分布)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencySample {
+    pub timestamp: i64,
+    pub model: Option<String>,
+    /// 完整耗时 (流式请求为流结束时)
+    pub duration_ms: u64,
+    /// 首字节耗时 (响应头返回时)
+    pub ttfb_ms: Option<u64>,
+}
+
 pub struct ProxyMonitor {
     pub logs: RwLock<VecDeque<ProxyRequestLog>>,
+    latency_samples: RwLock<VecDeque<LatencySample>>,
     pub stats: RwLock<ProxyStats>,
     pub max_logs: usize,
     pub enabled: AtomicBool,
@@ -63,6 +78,7 @@ impl ProxyMonitor {
 
         Self {
             logs: RwLock::new(VecDeque::with_capacity(max_logs)),
+            latency_samples: RwLock::new(VecDeque::with_capacity(LATENCY_WINDOW_SIZE)),
             stats: RwLock::new(ProxyStats::default()),
             max_logs,
             enabled: AtomicBool::new(false), // Default to disabled
@@ -184,6 +200,25 @@ impl ProxyMonitor {
         }
     }
 
+    /// 记录一次延迟采样 (超出窗口时丢弃最旧的)
+    pub async fn record_latency(&self, model: Option<String>, duration_ms: u64, ttfb_ms: Option<u64>) {
+        let mut samples = self.latency_samples.write().await;
+        if samples.len() >= LATENCY_WINDOW_SIZE {
+            samples.pop_front();
+        }
+        samples.push_back(LatencySample {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            model,
+            duration_ms,
+            ttfb_ms,
+        });
+    }
+
+    /// 导出滚动窗口内的全部延迟采样 (按时间升序)
+    pub async fn export_latency_samples(&self) -> Vec<LatencySample> {
+        self.latency_samples.read().await.iter().cloned().collect()
+    }
+
     pub async fn get_logs(&self, limit: usize) -> Vec<ProxyRequestLog> {
         // Try to get from DB first for true history
         let db_result = tokio::task::spawn_blocking(move || {
@@ -248,6 +283,7 @@ impl ProxyMonitor {
     pub async fn clear(&self) {
         let mut logs = self.logs.write().await;
         logs.clear();
+        self.latency_samples.write().await.clear();
         let mut stats = self.stats.write().await;
         *stats = ProxyStats::default();
 
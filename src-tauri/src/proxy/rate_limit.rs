@@ -1,4 +1,6 @@
 use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
 use std::time::{SystemTime, Duration};
 use regex::Regex;
 
@@ -38,14 +40,54 @@ pub struct RateLimitInfo {
     pub model: Option<String>,
 }
 
-/// 失败计数过期时间：1小时（超过此时间未失败则重置计数）
-const FAILURE_COUNT_EXPIRY_SECONDS: u64 = 3600;
+/// 限流参数 (可运行时调整，无需重新编译)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// 统计窗口 (毫秒)：超过此时间未失败则重置连续失败计数
+    pub window_ms: u64,
+    /// 窗口内允许的最大请求数 (预留给计划中的全局限流器, 目前尚未生效)
+    pub max_requests: u32,
+    /// RATE_LIMIT_EXCEEDED (TPM/RPM) 且上游未给出重试时间时的冷却时长 (毫秒)
+    pub cooldown_ms: u64,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            window_ms: 3_600_000,
+            max_requests: 60,
+            cooldown_ms: 5_000,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.window_ms == 0 {
+            return Err("window_ms must be greater than 0".to_string());
+        }
+        if self.max_requests == 0 {
+            return Err("max_requests must be greater than 0".to_string());
+        }
+        if self.cooldown_ms == 0 {
+            return Err("cooldown_ms must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+
+    /// 冷却时长向上取整到秒 (锁定以秒为单位)
+    fn cooldown_secs(&self) -> u64 {
+        self.cooldown_ms.div_ceil(1000)
+    }
+}
 
 /// 限流跟踪器
 pub struct RateLimitTracker {
     limits: DashMap<String, RateLimitInfo>,
     /// 连续失败计数（用于智能指数退避），带时间戳用于自动过期
     failure_counts: DashMap<String, (u32, SystemTime)>,
+    /// 限流参数
+    config: RwLock<RateLimitConfig>,
 }
 
 impl RateLimitTracker {
@@ -53,6 +95,19 @@ impl RateLimitTracker {
         Self {
             limits: DashMap::new(),
             failure_counts: DashMap::new(),
+            config: RwLock::new(RateLimitConfig::default()),
+        }
+    }
+
+    /// 获取当前限流参数
+    pub fn config(&self) -> RateLimitConfig {
+        self.config.read().map(|c| *c).unwrap_or_default()
+    }
+
+    /// 更新限流参数
+    pub fn set_config(&self, config: RateLimitConfig) {
+        if let Ok(mut lock) = self.config.write() {
+            *lock = config;
         }
     }
     
@@ -218,6 +273,8 @@ impl RateLimitTracker {
             retry_after_sec = self.parse_retry_time_from_body(body);
         }
         
+        let config = self.config();
+
         // 4. 处理默认值与软避让逻辑（根据限流类型设置不同默认值）
         let retry_sec = match retry_after_sec {
             Some(s) => {
@@ -236,9 +293,9 @@ impl RateLimitTracker {
                     // 暂时保持 account_id，这样如果一个模型一直挂，也会增加计数，符合逻辑。
                     let mut entry = self.failure_counts.entry(account_id.to_string()).or_insert((0, now));
 
-                    let elapsed = now.duration_since(entry.1).unwrap_or(Duration::from_secs(0)).as_secs();
-                    if elapsed > FAILURE_COUNT_EXPIRY_SECONDS {
-                        tracing::debug!("账号 {} 失败计数已过期（{}秒），重置为 0", account_id, elapsed);
+                    let elapsed = now.duration_since(entry.1).unwrap_or(Duration::from_secs(0));
+                    if elapsed > Duration::from_millis(config.window_ms) {
+                        tracing::debug!("账号 {} 失败计数已过期（{}秒），重置为 0", account_id, elapsed.as_secs());
                         *entry = (0, now);
                     }
                     entry.0 += 1;
@@ -267,8 +324,9 @@ impl RateLimitTracker {
                     },
                     RateLimitReason::RateLimitExceeded => {
                         // 速率限制 (TPM/RPM)
-                        tracing::debug!("检测到速率限制 (RATE_LIMIT_EXCEEDED)，使用默认值 5秒");
-                        5
+                        let lockout = config.cooldown_secs();
+                        tracing::debug!("检测到速率限制 (RATE_LIMIT_EXCEEDED)，使用默认值 {}秒", lockout);
+                        lockout
                     },
                     RateLimitReason::ModelCapacityExhausted => {
                         // 模型容量耗尽
@@ -571,6 +629,26 @@ impl Default for RateLimitTracker {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_rate_limit_cooldown_from_config() {
+        let tracker = RateLimitTracker::new();
+        tracker.set_config(RateLimitConfig {
+            cooldown_ms: 12_500,
+            ..RateLimitConfig::default()
+        });
+        let body = r#"{"error":{"details":[{"reason":"RATE_LIMIT_EXCEEDED"}]}}"#;
+        let info = tracker.parse_from_error("acc1", 429, None, body, None, &[]).unwrap();
+        assert_eq!(info.reason, RateLimitReason::RateLimitExceeded);
+        assert_eq!(info.retry_after_sec, 13);
+    }
+
+    #[test]
+    fn test_rate_limit_config_validate() {
+        assert!(RateLimitConfig::default().validate().is_ok());
+        assert!(RateLimitConfig { window_ms: 0, ..RateLimitConfig::default() }.validate().is_err());
+        assert!(RateLimitConfig { max_requests: 0, ..RateLimitConfig::default() }.validate().is_err());
+    }
+
     #[test]
     fn test_parse_retry_time_minutes_seconds() {
         let tracker = RateLimitTracker::new();
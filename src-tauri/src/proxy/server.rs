@@ -3358,6 +3358,10 @@ struct OpencodeSyncRequest {
     #[serde(default)]
     sync_accounts: bool,
     pub models: Option<Vec<String>>,
+    #[serde(default)]
+    options: Option<crate::proxy::opencode_sync::OpencodeSyncOptions>,
+    #[serde(default)]
+    parallel: Option<bool>,
 }
 
 async fn admin_execute_opencode_sync(
@@ -3368,9 +3372,11 @@ async fn admin_execute_opencode_sync(
         payload.api_key,
         Some(payload.sync_accounts),
         payload.models,
+        payload.options,
+        payload.parallel,
     )
     .await
-    .map(|_| StatusCode::OK)
+    .map(Json)
     .map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -3402,7 +3408,9 @@ async fn admin_get_opencode_config_content(
     Json(payload): Json<GetOpencodeConfigRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
     let file_name = payload.file_name;
-    tokio::task::spawn_blocking(move || crate::proxy::opencode_sync::read_opencode_config_content(file_name))
+    tokio::task::spawn_blocking(move || {
+        crate::proxy::opencode_sync::read_opencode_config_content(file_name, None)
+    })
         .await
         .map_err(|e| (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -3420,12 +3428,13 @@ async fn admin_get_opencode_config_content(
 struct OpencodeClearRequest {
     proxy_url: Option<String>,
     clear_legacy: Option<bool>,
+    legacy_providers: Option<Vec<String>>,
 }
 
 async fn admin_execute_opencode_clear(
     Json(payload): Json<OpencodeClearRequest>,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    crate::proxy::opencode_sync::execute_opencode_clear(payload.proxy_url, payload.clear_legacy)
+    crate::proxy::opencode_sync::execute_opencode_clear(payload.proxy_url, payload.clear_legacy, payload.legacy_providers)
         .await
         .map(|_| StatusCode::OK)
         .map_err(|e| (
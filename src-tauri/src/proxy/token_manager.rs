@@ -2186,6 +2186,12 @@ impl TokenManager {
         tracing::debug!("Circuit breaker configuration updated");
     }
 
+    /// 更新限流参数
+    pub fn update_rate_limit_config(&self, config: crate::proxy::rate_limit::RateLimitConfig) {
+        self.rate_limit_tracker.set_config(config);
+        tracing::debug!("Rate limit configuration updated: {:?}", config);
+    }
+
     /// [NEW] 获取熔断器配置
     pub async fn get_circuit_breaker_config(&self) -> crate::models::CircuitBreakerConfig {
         self.circuit_breaker_config.read().await.clone()
@@ -14,6 +14,7 @@ export interface Account {
     proxy_disabled_at?: number;
     protected_models?: string[];
     custom_label?: string;  // 用户自定义标签
+    allowed_families?: string[];  // 允许的模型家族，未设置 = 不限制
     created_at: number;
     last_used: number;
 }
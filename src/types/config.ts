@@ -62,6 +62,9 @@ export interface GlobalSystemPromptConfig {
 export interface DebugLoggingConfig {
     enabled: boolean;
     output_dir?: string;
+    filter_trace_ids?: string[];
+    max_capture_bytes?: number;
+    output_format?: 'pretty_json' | 'compact_json' | 'ndjson';
 }
 
 export type SchedulingMode = 'CacheFirst' | 'Balance' | 'PerformanceFirst';